@@ -2,6 +2,7 @@
 #define INCLUDES_H
 
 #include <algorithm>
+#include <cmath>
 #include <iostream>
 #include <istream>
 #include <iterator>
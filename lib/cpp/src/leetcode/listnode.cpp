@@ -1,6 +1,7 @@
 #include "listnode.h"
 
 #include <sstream>
+#include <unordered_set>
 #include <vector>
 
 ListNode* listNodeFrom(std::vector<int> input) {
@@ -32,6 +33,23 @@ std::ostream& operator<<(std::ostream& os, const ListNode& value) {
     return os;
 }
 
+bool equals(const ListNode* lhs, const ListNode* rhs) {
+    return lhs && rhs ? *lhs == *rhs : lhs == rhs;
+}
+
+std::ostream& operator<<(std::ostream& os, const ListNode* value) {
+    return value ? os << *value : os << "[]";
+}
+
 void destroy(ListNode* ln) {
     if (ln) { destroy(ln->next); delete ln; }
 }
+
+void destroy(const std::vector<ListNode*>& lns) {
+    std::unordered_set<ListNode*> nodes;
+
+    for (ListNode *node : lns) {
+        while (node && nodes.insert(node).second) node = node->next;
+    }
+    for (ListNode *node : nodes) delete node;
+}
@@ -18,6 +18,9 @@ ListNode* listNodeFrom(std::vector<int> input);
 bool operator==(const ListNode& lhs, const ListNode& rhs);
 bool operator!=(const ListNode& lhs, const ListNode& rhs);
 std::ostream& operator<<(std::ostream& os, const ListNode& value);
+bool equals(const ListNode* lhs, const ListNode* rhs);
+std::ostream& operator<<(std::ostream& os, const ListNode* value);
 void destroy(ListNode* ln);
+void destroy(const std::vector<ListNode*>& lns);
 
 #endif
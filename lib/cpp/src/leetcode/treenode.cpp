@@ -0,0 +1,78 @@
+#include "treenode.h"
+
+#include <optional>
+#include <queue>
+#include <string>
+#include <unordered_set>
+#include <vector>
+
+TreeNode* treeNodeFrom(std::vector<std::optional<int>> input) {
+    if (input.empty() || !input[0]) return nullptr;
+
+    TreeNode *root = new TreeNode(*input[0]);
+    std::queue<TreeNode*> q({ root });
+
+    for (size_t i = 1; i < input.size() && !q.empty(); i += 2) {
+        TreeNode *node = q.front(); q.pop();
+
+        if (input[i]) { node->left = new TreeNode(*input[i]); q.push(node->left); }
+        if (i + 1 < input.size() && input[i + 1]) { node->right = new TreeNode(*input[i + 1]); q.push(node->right); }
+    }
+
+    return root;
+}
+
+bool operator==(const TreeNode& lhs, const TreeNode& rhs) {
+    return (lhs.val == rhs.val)
+        && !((lhs.left && !rhs.left) || (!lhs.left && rhs.left))
+        && !((lhs.right && !rhs.right) || (!lhs.right && rhs.right))
+        && (!lhs.left || (*(lhs.left) == *(rhs.left)))
+        && (!lhs.right || (*(lhs.right) == *(rhs.right)));
+}
+
+bool operator!=(const TreeNode& lhs, const TreeNode& rhs) {
+    return !(lhs == rhs);
+}
+
+std::ostream& operator<<(std::ostream& os, const TreeNode& value) {
+    std::vector<std::string> vals;
+    std::queue<const TreeNode*> q({ &value });
+
+    while (!q.empty()) {
+        const TreeNode *node = q.front(); q.pop();
+
+        if (node) { vals.push_back(std::to_string(node->val)); q.push(node->left); q.push(node->right); }
+        else vals.push_back("null");
+    }
+    while (vals.back() == "null") vals.pop_back();
+
+    os << "[";
+    for (size_t i = 0; i < vals.size(); i++) os << (i ? "," : "") << vals[i];
+    os << "]";
+
+    return os;
+}
+
+bool equals(const TreeNode* lhs, const TreeNode* rhs) {
+    return lhs && rhs ? *lhs == *rhs : lhs == rhs;
+}
+
+std::ostream& operator<<(std::ostream& os, const TreeNode* value) {
+    return value ? os << *value : os << "[]";
+}
+
+void destroy(TreeNode* tn) {
+    if (tn) { destroy(tn->left); destroy(tn->right); delete tn; }
+}
+
+void destroy(const std::vector<TreeNode*>& tns) {
+    std::unordered_set<TreeNode*> nodes;
+    std::vector<TreeNode*> stack(tns);
+
+    while (!stack.empty()) {
+        TreeNode *node = stack.back(); stack.pop_back();
+
+        if (node && nodes.insert(node).second) { stack.push_back(node->left); stack.push_back(node->right); }
+    }
+    for (TreeNode *node : nodes) delete node;
+}
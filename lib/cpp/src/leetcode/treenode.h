@@ -0,0 +1,27 @@
+#ifndef TREENODE_H
+#define TREENODE_H
+
+#include <optional>
+#include <ostream>
+#include <vector>
+
+struct TreeNode {
+    int val;
+    TreeNode *left;
+    TreeNode *right;
+
+    TreeNode() : val(0), left(nullptr), right(nullptr) {}
+    TreeNode(int x) : val(x), left(nullptr), right(nullptr) {}
+    TreeNode(int x, TreeNode *left, TreeNode *right) : val(x), left(left), right(right) {}
+};
+
+TreeNode* treeNodeFrom(std::vector<std::optional<int>> input);
+bool operator==(const TreeNode& lhs, const TreeNode& rhs);
+bool operator!=(const TreeNode& lhs, const TreeNode& rhs);
+std::ostream& operator<<(std::ostream& os, const TreeNode& value);
+bool equals(const TreeNode* lhs, const TreeNode* rhs);
+std::ostream& operator<<(std::ostream& os, const TreeNode* value);
+void destroy(TreeNode* tn);
+void destroy(const std::vector<TreeNode*>& tns);
+
+#endif
@@ -0,0 +1,35 @@
+package leetcode
+
+import (
+	"strconv"
+	"strings"
+)
+
+// ListNode is a node of a singly-linked list.
+type ListNode struct {
+	Val  int
+	Next *ListNode
+}
+
+// ListNodeFrom constructs a ListNode from the values in input.
+func ListNodeFrom(input []int) *ListNode {
+	dummy := &ListNode{}
+
+	tail := dummy
+	for _, val := range input {
+		tail.Next = &ListNode{Val: val}
+		tail = tail.Next
+	}
+
+	return dummy.Next
+}
+
+// String returns the values of the list as a bracketed sequence.
+func (l *ListNode) String() string {
+	vals := []string{}
+	for node := l; node != nil; node = node.Next {
+		vals = append(vals, strconv.Itoa(node.Val))
+	}
+
+	return "[" + strings.Join(vals, ",") + "]"
+}
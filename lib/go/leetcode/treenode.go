@@ -0,0 +1,62 @@
+package leetcode
+
+import (
+	"strconv"
+	"strings"
+)
+
+// TreeNode is a node of a binary tree.
+type TreeNode struct {
+	Val   int
+	Left  *TreeNode
+	Right *TreeNode
+}
+
+// TreeNodeFrom constructs a TreeNode from its level-order encoding input, in which nil marks a missing node.
+func TreeNodeFrom(input []any) *TreeNode {
+	if len(input) == 0 || input[0] == nil {
+		return nil
+	}
+
+	root := &TreeNode{Val: input[0].(int)}
+	queue := []*TreeNode{root}
+
+	for i := 1; i < len(input) && len(queue) > 0; i += 2 {
+		node := queue[0]
+		queue = queue[1:]
+
+		if input[i] != nil {
+			node.Left = &TreeNode{Val: input[i].(int)}
+			queue = append(queue, node.Left)
+		}
+		if i+1 < len(input) && input[i+1] != nil {
+			node.Right = &TreeNode{Val: input[i+1].(int)}
+			queue = append(queue, node.Right)
+		}
+	}
+
+	return root
+}
+
+// String returns the level-order encoding of the tree.
+func (t *TreeNode) String() string {
+	vals := []string{}
+	queue := []*TreeNode{t}
+
+	for len(queue) > 0 {
+		node := queue[0]
+		queue = queue[1:]
+
+		if node != nil {
+			vals = append(vals, strconv.Itoa(node.Val))
+			queue = append(queue, node.Left, node.Right)
+		} else {
+			vals = append(vals, "null")
+		}
+	}
+	for len(vals) > 0 && vals[len(vals)-1] == "null" {
+		vals = vals[:len(vals)-1]
+	}
+
+	return "[" + strings.Join(vals, ",") + "]"
+}
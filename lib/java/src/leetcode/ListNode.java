@@ -0,0 +1,43 @@
+package leetcode;
+
+import java.util.Objects;
+
+public class ListNode {
+    public int val;
+    public ListNode next;
+
+    public ListNode() {}
+    public ListNode(int val) { this.val = val; }
+    public ListNode(int val, ListNode next) { this.val = val; this.next = next; }
+
+    /** Constructs a `ListNode` from `input`. */
+    public static ListNode from(int[] input) {
+        ListNode res = new ListNode(0);
+        ListNode node = res;
+
+        for (int n : input) { node.next = new ListNode(n); node = node.next; }
+
+        return res.next;
+    }
+
+    @Override
+    public boolean equals(Object other) {
+        if (!(other instanceof ListNode)) return false;
+
+        ListNode rhs = (ListNode) other;
+        return val == rhs.val && Objects.equals(next, rhs.next);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(val, next);
+    }
+
+    @Override
+    public String toString() {
+        StringBuilder sb = new StringBuilder("[");
+        for (ListNode node = this; node != null; node = node.next) sb.append(node.val).append(node.next != null ? "," : "");
+
+        return sb.append("]").toString();
+    }
+}
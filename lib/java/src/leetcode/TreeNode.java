@@ -0,0 +1,64 @@
+package leetcode;
+
+import java.util.ArrayDeque;
+import java.util.ArrayList;
+import java.util.LinkedList;
+import java.util.List;
+import java.util.Objects;
+import java.util.Queue;
+
+public class TreeNode {
+    public int val;
+    public TreeNode left;
+    public TreeNode right;
+
+    public TreeNode() {}
+    public TreeNode(int val) { this.val = val; }
+    public TreeNode(int val, TreeNode left, TreeNode right) { this.val = val; this.left = left; this.right = right; }
+
+    /** Constructs a `TreeNode` from its level-order encoding `input`. */
+    public static TreeNode from(Integer[] input) {
+        if (input.length == 0 || input[0] == null) return null;
+
+        TreeNode root = new TreeNode(input[0]);
+        Queue<TreeNode> queue = new ArrayDeque<>(List.of(root));
+
+        for (int i = 1; i < input.length && !queue.isEmpty(); i += 2) {
+            TreeNode node = queue.poll();
+
+            if (input[i] != null) { node.left = new TreeNode(input[i]); queue.add(node.left); }
+            if (i + 1 < input.length && input[i + 1] != null) { node.right = new TreeNode(input[i + 1]); queue.add(node.right); }
+        }
+
+        return root;
+    }
+
+    @Override
+    public boolean equals(Object other) {
+        if (!(other instanceof TreeNode)) return false;
+
+        TreeNode rhs = (TreeNode) other;
+        return val == rhs.val && Objects.equals(left, rhs.left) && Objects.equals(right, rhs.right);
+    }
+
+    @Override
+    public int hashCode() {
+        return Objects.hash(val, left, right);
+    }
+
+    @Override
+    public String toString() {
+        List<String> vals = new ArrayList<>();
+        Queue<TreeNode> queue = new LinkedList<>(List.of(this));
+
+        while (!queue.isEmpty()) {
+            TreeNode node = queue.poll();
+
+            if (node != null) { vals.add(String.valueOf(node.val)); queue.add(node.left); queue.add(node.right); }
+            else vals.add("null");
+        }
+        while (vals.get(vals.size() - 1).equals("null")) vals.remove(vals.size() - 1);
+
+        return "[" + String.join(",", vals) + "]";
+    }
+}
@@ -0,0 +1,47 @@
+from collections import deque
+from typing import Optional
+
+
+class TreeNode:
+    def __init__(self, val=0, left=None, right=None):
+        self.val = val
+        self.left = left
+        self.right = right
+
+    def __repr__(self):
+        return f"TreeNode({self.val}, {self.left}, {self.right})"
+
+    def __eq__(self, other):
+        if isinstance(other, TreeNode):
+            return (
+                self.val == other.val
+                and (other.left is None if self.left is None else self.left == other.left)
+                and (other.right is None if self.right is None else self.right == other.right)
+            )
+        else:
+            return False
+
+
+def treeNodeFrom(input: list[Optional[int]]) -> Optional[TreeNode]:
+    """Constructs a `TreeNode` from its level-order encoding `input`"""
+    if not input or input[0] is None:
+        return None
+
+    root = TreeNode(input[0])
+    queue = deque([root])
+    i = 1
+
+    while queue and i < len(input):
+        node = queue.popleft()
+
+        if input[i] is not None:
+            node.left = TreeNode(input[i])
+            queue.append(node.left)
+        i += 1
+
+        if i < len(input) and input[i] is not None:
+            node.right = TreeNode(input[i])
+            queue.append(node.right)
+        i += 1
+
+    return root
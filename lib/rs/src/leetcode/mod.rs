@@ -1,3 +1,5 @@
 mod listnode;
+mod treenode;
 
 pub use self::listnode::ListNode;
+pub use self::treenode::TreeNode;
@@ -0,0 +1,50 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct TreeNode {
+    pub val: i32,
+    pub left: Option<Rc<RefCell<TreeNode>>>,
+    pub right: Option<Rc<RefCell<TreeNode>>>,
+}
+
+impl TreeNode {
+    #[rustfmt::skip]
+    #[must_use]
+    #[inline]
+    pub fn new(val: i32) -> Self {
+        TreeNode {
+            val,
+            left: None,
+            right: None
+        }
+    }
+
+    /// Constructs a `TreeNode` from its level-order encoding `input`.
+    #[must_use]
+    pub fn from(input: Vec<Option<i32>>) -> Option<Rc<RefCell<Self>>> {
+        let mut it = input.into_iter();
+        let root = Rc::new(RefCell::new(TreeNode::new(it.next()??)));
+        let mut queue = VecDeque::from([Rc::clone(&root)]);
+
+        while let Some(node) = queue.pop_front() {
+            let mut node = node.borrow_mut();
+            let node = &mut *node;
+
+            for child in [&mut node.left, &mut node.right] {
+                match it.next() {
+                    Some(Some(val)) => {
+                        let new = Rc::new(RefCell::new(TreeNode::new(val)));
+                        queue.push_back(Rc::clone(&new));
+                        *child = Some(new);
+                    }
+                    Some(None) => {}
+                    None => return Some(root),
+                }
+            }
+        }
+
+        Some(root)
+    }
+}
@@ -0,0 +1,25 @@
+/** A node of a singly-linked list. */
+export class ListNode {
+    val: number;
+    next: ListNode | null;
+
+    constructor(val?: number, next?: ListNode | null) {
+        this.val = val === undefined ? 0 : val;
+        this.next = next === undefined ? null : next;
+    }
+
+    /** Returns the values of the list, so that it is serialized as an array. */
+    toJSON(): number[] {
+        const vals: number[] = [];
+        for (let node: ListNode | null = this; node !== null; node = node.next) {
+            vals.push(node.val);
+        }
+
+        return vals;
+    }
+}
+
+/** Constructs a `ListNode` from the values in `input`. */
+export function listNodeFrom(input: number[]): ListNode | null {
+    return input.reduceRight<ListNode | null>((next, val) => new ListNode(val, next), null);
+}
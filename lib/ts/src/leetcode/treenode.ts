@@ -0,0 +1,60 @@
+/** A node of a binary tree. */
+export class TreeNode {
+    val: number;
+    left: TreeNode | null;
+    right: TreeNode | null;
+
+    constructor(val?: number, left?: TreeNode | null, right?: TreeNode | null) {
+        this.val = val === undefined ? 0 : val;
+        this.left = left === undefined ? null : left;
+        this.right = right === undefined ? null : right;
+    }
+
+    /** Returns the level-order encoding of the tree, so that it is serialized as an array. */
+    toJSON(): (number | null)[] {
+        const vals: (number | null)[] = [];
+        const queue: (TreeNode | null)[] = [this];
+
+        for (let i = 0; i < queue.length; i++) {
+            const node = queue[i];
+
+            if (node !== null) {
+                vals.push(node.val);
+                queue.push(node.left, node.right);
+            } else {
+                vals.push(null);
+            }
+        }
+        while (vals.length > 0 && vals[vals.length - 1] === null) {
+            vals.pop();
+        }
+
+        return vals;
+    }
+}
+
+/** Constructs a `TreeNode` from its level-order encoding `input`. */
+export function treeNodeFrom(input: (number | null)[]): TreeNode | null {
+    if (input.length === 0 || input[0] === null) {
+        return null;
+    }
+
+    const root = new TreeNode(input[0]);
+    const queue = [root];
+
+    for (let i = 1, j = 0; i < input.length && j < queue.length; i += 2, j++) {
+        const node = queue[j];
+        const [left, right] = [input[i], input[i + 1]];
+
+        if (left !== null) {
+            node.left = new TreeNode(left);
+            queue.push(node.left);
+        }
+        if (right !== null && right !== undefined) {
+            node.right = new TreeNode(right);
+            queue.push(node.right);
+        }
+    }
+
+    return root;
+}
@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+use std::error::Error;
+
+use colored::Colorize;
+use rand::Rng;
+
+use super::config::Config;
+use super::grader::Status;
+use super::lang::Lang;
+use super::source::{Cache, Difficulty, Filters, Listing, Page, ProblemId, Source};
+
+/// The maximum number of characters of a title shown in the table of problems.
+const TITLE_WIDTH: usize = 60;
+
+/// The maximum number of problems drawn at random when picking one to attempt.
+const MAX_DRAWS: usize = 20;
+
+/// Prints page `page` of the problems of `source` matching `filters`, `per_page` problems to a page, as a table along
+/// with the local status of each problem.
+pub fn list(source: &Source, filters: &Filters, page: usize, per_page: usize, config: &Config) -> Result<(), Box<dyn Error>> {
+    let Page { listings, total } = source.list(filters, (page - 1) * per_page, per_page)?;
+    let pages = total.div_ceil(per_page).max(1);
+
+    if listings.is_empty() {
+        println!("\nNo problems found on page {page} of {pages}");
+        return Ok(());
+    }
+
+    println!(
+        "\n{}",
+        format!("{:>6}  {:<TITLE_WIDTH$}  {:<10}  {:>10}  {}", "ID", "Title", "Difficulty", "Acceptance", "Status").bold()
+    );
+    for listing in &listings {
+        print_row(listing, source, config);
+    }
+    println!("\nPage {} of {}, {} problems in total", page.to_string().bold(), pages.to_string().bold(), total);
+
+    Ok(())
+}
+
+/// Prints `listing` of `source` as a row of the table of problems.
+fn print_row(listing: &Listing, source: &Source, config: &Config) {
+    let suffix = if listing.paid_only { " (premium)" } else { "" };
+    let width = TITLE_WIDTH - suffix.len();
+    let title = if listing.title.chars().count() > width {
+        listing.title.chars().take(width - 3).collect::<String>() + "..." + suffix
+    } else {
+        listing.title.clone() + suffix
+    };
+
+    let difficulty = format!("{:<10}", listing.difficulty.to_string());
+    let status = Status::of(&listing.id, source, config);
+
+    println!(
+        "{:>6}  {:<TITLE_WIDTH$}  {}  {:>9.1}%  {}",
+        listing.id.blue().bold(),
+        title,
+        match listing.difficulty {
+            Difficulty::Easy => difficulty.green(),
+            Difficulty::Medium => difficulty.yellow(),
+            Difficulty::Hard => difficulty.red(),
+        },
+        listing.acceptance,
+        match status {
+            Status::Unattempted => status.to_string().normal(),
+            Status::Attempted => status.to_string().yellow(),
+            Status::Passed => status.to_string().green().bold(),
+            Status::Failed => status.to_string().red().bold(),
+        }
+    );
+}
+
+/// Returns the ID of a problem of `source` picked at random among those matching `filters` with starter code in
+/// language `lang`, excluding premium problems and those which already have a directory under the solution root.
+///
+/// Problems are drawn one at a time from the matching ones, and up to [`MAX_DRAWS`] of them are tried before giving up.
+pub fn random(
+    source: &Source, filters: &Filters, lang: &Lang, config: &Config, cache: &Cache,
+) -> Result<ProblemId, Box<dyn Error>> {
+    let total = source.list(filters, 0, 1)?.total;
+    let mut rng = rand::thread_rng();
+    let mut drawn = HashSet::new();
+
+    while drawn.len() < total.min(MAX_DRAWS) {
+        let skip = rng.gen_range(0..total);
+        if !drawn.insert(skip) {
+            continue;
+        }
+
+        let Some(listing) = source.list(filters, skip, 1)?.listings.into_iter().next() else {
+            continue;
+        };
+        if listing.paid_only || config.prob_dir(source, &listing.id).exists() {
+            continue;
+        }
+        if source.query(&listing.id, lang, config, cache)?.1.is_some() {
+            return Ok(listing.id);
+        }
+    }
+
+    Err(format!("No unsolved problem of {source} in {lang} matches the filters, out of {} drawn", drawn.len()).into())
+}
@@ -1,17 +1,22 @@
+use std::fs::File;
+use std::io::Write;
+use std::num::NonZeroUsize;
+use std::os::fd::FromRawFd;
 use std::process;
+use std::time::Duration;
 
 use clap::{Parser, Subcommand};
 use colored::{ColoredString, Colorize};
 
+use super::catalog;
 use super::colorize::MoreColorize;
 use super::config::Config;
 use super::dev_env;
 use super::fetcher;
-use super::grader;
+use super::grader::{self, Format, Limits};
 use super::lang::Lang;
-use super::source::Source;
-
-const LEETCODE_MAX_PROBLEM_ID: i64 = 3023;
+use super::source::{Cache, Difficulty, Filters, ProblemId, Source};
+use super::submitter;
 
 /// The command-line interface for `proctor`.
 #[derive(Parser)]
@@ -37,9 +42,8 @@ enum Commands {
     },
     /// Fetches the problem
     Fetch {
-        /// Problem ID
-        #[arg(value_parser = clap::value_parser!(u16).range(1..=LEETCODE_MAX_PROBLEM_ID))]
-        id: u16,
+        /// Problem ID, or for LeetCode problems, also its title slug or URL
+        id: String,
 
         /// Code language to fetch problem
         lang: Lang,
@@ -48,15 +52,86 @@ enum Commands {
         #[arg(long)]
         overwrite: bool,
 
+        /// Query question data even if it's cached
+        #[arg(long, conflicts_with = "offline")]
+        refresh: bool,
+
+        /// Render from cached question data only, failing instead of querying the network
+        #[arg(long)]
+        offline: bool,
+
+        /// Source of problem
+        #[arg(default_value_t = Source::LeetCode)]
+        source: Source,
+    },
+    /// Fetches today's LeetCode daily challenge problem
+    Daily {
+        /// Code language to fetch problem, else the configured default language
+        lang: Option<Lang>,
+
+        /// Overwrite existing solution
+        #[arg(long)]
+        overwrite: bool,
+    },
+    /// Lists problems matching the filters, along with their local status
+    List {
+        /// Only list problems of the difficulty
+        #[arg(short, long)]
+        difficulty: Option<Difficulty>,
+
+        /// Only list problems with the topic tag, given as its slug (e.g. `dynamic-programming`)
+        #[arg(short, long = "tag", value_name = "TAG")]
+        tags: Vec<String>,
+
+        /// Only list problems matching the keywords
+        #[arg(short, long)]
+        keywords: Option<String>,
+
+        /// Page of problems to list
+        #[arg(short, long, default_value = "1")]
+        page: NonZeroUsize,
+
+        /// Number of problems in a page
+        #[arg(long, default_value = "50")]
+        per_page: NonZeroUsize,
+
+        /// Source of problems
+        #[arg(default_value_t = Source::LeetCode)]
+        source: Source,
+    },
+    /// Fetches a problem picked at random among the unsolved ones matching the filters
+    Random {
+        /// Code language to fetch problem, else the configured default language
+        lang: Option<Lang>,
+
+        /// Only pick among problems of the difficulty
+        #[arg(short, long)]
+        difficulty: Option<Difficulty>,
+
+        /// Only pick among problems with the topic tag, given as its slug (e.g. `dynamic-programming`)
+        #[arg(short, long = "tag", value_name = "TAG")]
+        tags: Vec<String>,
+
+        /// Source of problems
+        #[arg(default_value_t = Source::LeetCode)]
+        source: Source,
+    },
+    /// Submits the solution to LeetCode and prints the verdict
+    Submit {
+        /// Problem ID, or for LeetCode problems, also its title slug or URL
+        id: String,
+
+        /// Code language of solution
+        lang: Lang,
+
         /// Source of problem
         #[arg(default_value_t = Source::LeetCode)]
         source: Source,
     },
     /// Compile and test solution
     Run {
-        /// Problem ID
-        #[arg(value_parser = clap::value_parser!(u16).range(1..=LEETCODE_MAX_PROBLEM_ID))]
-        id: u16,
+        /// Problem ID, or for LeetCode problems, also its title slug or URL
+        id: String,
 
         /// Code language to compile and test in
         lang: Lang,
@@ -64,12 +139,107 @@ enum Commands {
         /// Source of problem
         #[arg(default_value_t = Source::LeetCode)]
         source: Source,
+
+        /// Time limit in seconds of each run of the solution, overriding the `timeout` configured for the language
+        #[arg(short, long, value_name = "SECS", value_parser = seconds)]
+        timeout: Option<Duration>,
+
+        /// Limit in MB on the address space of each run of the solution, overriding the `memory_limit` configured for
+        /// the language
+        #[arg(short, long, value_name = "MB")]
+        memory_limit: Option<u64>,
+
+        /// Format to emit the outcome in, `json` or `junit` printing only the outcome to stdout, and the rest to stderr
+        #[arg(short, long, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Compile solution with optimizations and time its runs
+    Bench {
+        /// Problem ID, or for LeetCode problems, also its title slug or URL
+        id: String,
+
+        /// Code language to compile and time in
+        lang: Lang,
+
+        /// Source of problem
+        #[arg(default_value_t = Source::LeetCode)]
+        source: Source,
+
+        /// Number of times to run the solution
+        #[arg(short = 'n', long, default_value = "10")]
+        runs: NonZeroUsize,
     },
 }
 
+/// Parses `secs` as a non-negative number of seconds.
+fn seconds(secs: &str) -> Result<Duration, String> {
+    secs.parse::<f64>()
+        .map_err(|err| err.to_string())
+        .and_then(|secs| Duration::try_from_secs_f64(secs).map_err(|err| err.to_string()))
+}
+
+/// Returns the ID of the problem of `source` referred to by `reference`, exiting if there's no such problem. For an
+/// already `fetched` problem, the ID is resolved without the network where possible.
+fn problem_id(reference: &str, source: &Source, cache: &Cache, fetched: bool) -> ProblemId {
+    let id = if fetched {
+        source.fetched_problem_id(reference, cache)
+    } else {
+        source.problem_id(reference, cache)
+    };
+
+    id.unwrap_or_else(|err| {
+        println!("{}: {err}, exiting proctor", "ERR".red().bold());
+        process::exit(1);
+    })
+}
+
+/// Returns `lang` if given, else the configured default language, exiting if neither is present.
+fn lang_or_default<'a>(lang: &'a Option<Lang>, config: &'a Config) -> &'a Lang {
+    lang.as_ref()
+        .or(config.default_lang.as_ref())
+        .unwrap_or_else(|| {
+            println!("{}: No language given nor configured as `default_lang`, exiting proctor", "ERR".red().bold());
+            process::exit(1);
+        })
+}
+
+/// Fetches problem `id` of `source` in `lang`, reporting whether it succeeded.
+fn fetch(id: &ProblemId, lang: &Lang, source: &Source, config: &Config, overwrite: bool, cache: &Cache) {
+    println!("Fetching problem {} in {}:", id.blue().bold(), lang.get_name().cyan().bold());
+
+    match fetcher::fetch(id, lang, source, config, overwrite, cache) {
+        Ok(_) => {
+            println!("\n{} fetched problem {}", "Successfully".green().bold(), id.blue().bold());
+        }
+        Err(err) => {
+            println!("{}!\n{}: {err}", "FAILED".red().bold(), "ERR".red().bold());
+        }
+    }
+}
+
+/// Diverts whatever is printed to stdout to stderr, and returns a handle to the original stdout, which is then left
+/// clean for machine-readable output.
+fn divert_stdout() -> File {
+    // SAFETY: The duplicated descriptor is owned by nothing else, and is valid if `dup` didn't fail.
+    unsafe {
+        let stdout = libc::dup(libc::STDOUT_FILENO);
+        if stdout < 0 || libc::dup2(libc::STDERR_FILENO, libc::STDOUT_FILENO) < 0 {
+            eprintln!("{}: Can't divert stdout to stderr, exiting proctor", "ERR".red().bold());
+            process::exit(1);
+        }
+
+        File::from_raw_fd(stdout)
+    }
+}
+
 impl Cli {
     /// Runs the `proctor` CLI app.
     pub fn run(&self) {
+        let mut stdout = match &self.command {
+            Commands::Run { format, .. } if *format != Format::Text => Some(divert_stdout()),
+            _ => None,
+        };
+
         let config = if let Ok((config, pathbuf)) = Config::read(&self.config) {
             println!(
                 "\n{} read {}, with config values:\n{:#?}",
@@ -109,26 +279,79 @@ impl Cli {
                     );
                 }
             }
-            Commands::Fetch { id, lang, overwrite, source } => {
-                let id = &format!("{id:0>4}");
+            Commands::Fetch { id, lang, overwrite, refresh, offline, source } => {
+                let cache = Cache::new(&config, *refresh, *offline);
+                let id = &problem_id(id, source, &cache, false);
 
-                println!("Fetching problem {} in {}:", id.blue().bold(), lang.get_name().cyan().bold());
+                fetch(id, lang, source, &config, *overwrite, &cache);
+            }
+            Commands::Daily { lang, overwrite } => {
+                let lang = lang_or_default(lang, &config);
+                let (source, cache) = (Source::LeetCode, Cache::new(&config, false, false));
+                let id = &source.daily(&cache).unwrap_or_else(|err| {
+                    println!("{}!\n{}: {err}, exiting proctor", "FAILED".red().bold(), "ERR".red().bold());
+                    process::exit(1);
+                });
 
-                match fetcher::fetch(id, lang, source, &config, *overwrite) {
-                    Ok((sol_file, desc_file)) => {
-                        println!("\n{} fetched problem {}", "Successfully".green().bold(), id.blue().bold());
-                    }
-                    Err(err) => {
-                        println!("{}!\n{}: {err}", "FAILED".red().bold(), "ERR".red().bold());
-                    }
+                fetch(id, lang, &source, &config, *overwrite, &cache);
+            }
+            Commands::List { difficulty, tags, keywords, page, per_page, source } => {
+                let filters = Filters { difficulty: difficulty.clone(), tags: tags.clone(), keywords: keywords.clone() };
+
+                println!("Listing problems of {}:", source.to_string().cyan().bold());
+
+                if let Err(err) = catalog::list(source, &filters, page.get(), per_page.get(), &config) {
+                    println!("{}!\n{}: {err}", "FAILED".red().bold(), "ERR".red().bold());
+                }
+            }
+            Commands::Random { lang, difficulty, tags, source } => {
+                let lang = lang_or_default(lang, &config);
+                let filters = Filters { difficulty: difficulty.clone(), tags: tags.clone(), ..Filters::default() };
+
+                println!("Picking a random problem of {}:", source.to_string().cyan().bold());
+
+                let cache = Cache::new(&config, false, false);
+                let id = &catalog::random(source, &filters, lang, &config, &cache).unwrap_or_else(|err| {
+                    println!("{}!\n{}: {err}, exiting proctor", "FAILED".red().bold(), "ERR".red().bold());
+                    process::exit(1);
+                });
+
+                fetch(id, lang, source, &config, false, &cache);
+            }
+            Commands::Submit { id, lang, source } => {
+                let cache = Cache::new(&config, false, false);
+                let id = &problem_id(id, source, &cache, false);
+
+                println!("Submitting {} solution to problem {}:", lang.get_name().cyan().bold(), id.blue().bold());
+
+                if let Err(err) = submitter::submit(id, lang, source, &config, &cache) {
+                    println!("{}!\n{}: {err}", "FAILED".red().bold(), "ERR".red().bold());
                 }
             }
-            Commands::Run { id, lang, source } => {
-                let id = &format!("{id:0>4}");
+            Commands::Run { id, lang, source, timeout, memory_limit, format } => {
+                let id = &problem_id(id, source, &Cache::new(&config, false, false), true);
 
                 println!("Proctoring {} solution to problem {}:", lang.get_name().cyan().bold(), id.blue().bold());
 
-                grader::run(id, lang, source, &config);
+                let limits = Limits {
+                    time: timeout.unwrap_or_else(|| config.timeout(lang)),
+                    memory: memory_limit.or_else(|| config.memory_limit(lang)),
+                };
+                let outcome = grader::run(id, lang, source, &config, limits);
+                if let (Some(stdout), Some(rendered)) = (&mut stdout, outcome.render(format)) {
+                    if let Err(err) = writeln!(stdout, "{rendered}") {
+                        println!("{}: Can't write outcome in {format}: {err}", "ERR".red().bold());
+                        process::exit(1);
+                    }
+                }
+            }
+            Commands::Bench { id, lang, source, runs } => {
+                let id = &problem_id(id, source, &Cache::new(&config, false, false), true);
+
+                println!("Benchmarking {} solution to problem {}:", lang.get_name().cyan().bold(), id.blue().bold());
+
+                let limits = Limits { time: config.timeout(lang), memory: config.memory_limit(lang) };
+                grader::bench(id, lang, source, &config, limits, runs.get());
             }
         }
     }
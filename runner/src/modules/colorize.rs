@@ -6,7 +6,7 @@ pub trait MoreColorize: Colorize {
     fn orange(self) -> ColoredString;
 }
 
-impl<'a> MoreColorize for &'a str {
+impl MoreColorize for &str {
     fn orange(self) -> ColoredString {
         self.truecolor(255, 165, 0)
     }
@@ -3,12 +3,18 @@ use std::error::Error;
 use std::fs::File;
 use std::io::{self, BufReader, Write};
 use std::path::PathBuf;
+use std::time::Duration;
 
 use colored::Colorize;
 use serde::Deserialize;
 use serde_json::Value;
 
 use super::colorize::MoreColorize;
+use super::lang::Lang;
+use super::source::Source;
+
+/// The time limit of each run of a solution, unless configured otherwise for its language.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
 
 /// `runner` config.
 #[derive(Debug, Deserialize)]
@@ -18,6 +24,10 @@ pub struct Config {
     #[serde(rename = "sol_dir")]
     pub sol_dir_str: String,
     pub lang: HashMap<String, Value>,
+    #[serde(default)]
+    pub default_lang: Option<Lang>,
+    #[serde(default)]
+    pub source: HashMap<String, Value>,
 }
 
 impl Config {
@@ -39,12 +49,44 @@ impl Config {
         }
 
         println!("{}: Can't read configuration, proceeding with default configuration", "WARNING".yellow().bold());
-        Ok((Config::new(String::new(), String::from("."), String::from("./data")), None))
+        Ok((Config::new(String::new(), String::from(".")), None))
     }
 
     /// Returns a [`Config`] with the specified configurations.
     pub fn new(project_dir_str: String, sol_dir_str: String) -> Self {
-        Config { project_dir_str, sol_dir_str, lang: HashMap::default() }
+        Config {
+            project_dir_str,
+            sol_dir_str,
+            lang: HashMap::default(),
+            default_lang: None,
+            source: HashMap::default(),
+        }
+    }
+
+    /// Returns the [`PathBuf`] to the directory of problem `id` of `source` under the solution root.
+    pub fn prob_dir(&self, source: &Source, id: &str) -> PathBuf {
+        PathBuf::from(&self.sol_dir_str)
+            .join(source.to_string())
+            .join(id)
+    }
+
+    /// Returns the time limit of each run of a solution in language `lang`, configured in seconds as its `timeout`.
+    pub fn timeout(&self, lang: &Lang) -> Duration {
+        self.lang
+            .get(&lang.to_string())
+            .and_then(|langconf| langconf.get("timeout"))
+            .and_then(Value::as_f64)
+            .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+            .unwrap_or(DEFAULT_TIMEOUT)
+    }
+
+    /// Returns the limit on the address space in MB of each run of a solution in language `lang`, configured as its
+    /// `memory_limit`, if any.
+    pub fn memory_limit(&self, lang: &Lang) -> Option<u64> {
+        self.lang
+            .get(&lang.to_string())
+            .and_then(|langconf| langconf.get("memory_limit"))
+            .and_then(Value::as_u64)
     }
 
     /// Returns the [`PathBuf`] to the testing bin file for language (with extension `ext`).
@@ -5,25 +5,31 @@ use std::fs;
 use std::path::PathBuf;
 
 use colored::Colorize;
-use strum::{EnumCount, IntoEnumIterator};
+use strum::IntoEnumIterator;
 
 use super::config::Config;
 use super::lang::Lang;
 
 pub use self::setup::{Setup, Setups};
 
-/// Sets up the dev environment.
+/// Sets up the dev environment for the languages configured in `config`.
 pub fn setup(config: &Config, overwrite: bool) -> Result<(), Box<dyn Error>> {
     fs::create_dir_all(PathBuf::from(&config.sol_dir_str))?;
 
-    Lang::iter()
-        .generate_setups(config)?
+    for lang in Lang::iter().filter(|lang| !config.lang.contains_key(&lang.to_string())) {
+        println!("{} has no entry in lang of config, skipping its setup", lang.get_name().cyan().bold());
+    }
+
+    let setups = Lang::iter().generate_setups(config)?;
+    let n = setups.len();
+
+    setups
         .into_iter()
         .enumerate()
         .try_for_each(|(i, (setup, additional_command))| {
             println!(
                 "{} Running setup for {}:",
-                format!("[{}/{}]", i + 1, Lang::COUNT).dimmed(),
+                format!("[{}/{}]", i + 1, n).dimmed(),
                 setup.lang.get_name().cyan().bold()
             );
 
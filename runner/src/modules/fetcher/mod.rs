@@ -5,16 +5,17 @@ use std::collections::HashMap;
 use std::error::Error;
 use std::fs;
 use std::io::{self, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use colored::Colorize;
 use html2md::{Handle, StructuredPrinter, TagHandler, TagHandlerFactory};
+use serde::Serialize;
 use tera::{Context, Tera, Value};
 
 use super::colorize::MoreColorize;
 use super::config::Config;
 use super::lang::Lang;
-use super::source::{MetaData, Source, Typ};
+use super::source::{Cache, Function, MetaData, Source, Typ, Variable};
 
 pub use request::{GraphQLResponse, Method, Request, Response};
 
@@ -45,10 +46,77 @@ fn render_desc(html: &str) -> String {
     html2md::parse_html_custom(html, &custom)
 }
 
-/// Renders `code` using the Jinja template `template_name`.
+/// A call to a method of a designed class, with its processed arguments and expected result.
+#[derive(Serialize)]
+struct Call<'a> {
+    method: &'a str,
+    args: Vec<(&'a str, &'a Typ, String)>,
+    returns: &'a Typ,
+    expected: Option<String>,
+}
+
+/// Processes the paired operations and arguments in `examples` of a design question into pairs of constructor
+/// arguments and the sequence of [`Call`]s to replay against the class.
+#[allow(clippy::type_complexity)]
+fn replay<'a>(
+    lang: &Lang, constructor: &'a [Variable], methods: &'a [Function], examples: &str, outputs: &[String],
+) -> Result<Vec<(Vec<(&'a str, &'a Typ, String)>, Vec<Call<'a>>)>, Box<dyn Error>> {
+    let process_args = |params: &'a [Variable], args: &[Value]| {
+        params
+            .iter()
+            .zip(args)
+            .map(|(v, arg)| (v.name.as_str(), &v.typ, lang.process(&v.typ, &arg.to_string())))
+            .collect::<Vec<_>>()
+    };
+
+    examples
+        .lines()
+        .collect::<Vec<&str>>()
+        .chunks_exact(2)
+        .enumerate()
+        .map(|(i, example)| {
+            let operations = serde_json::from_str::<Vec<String>>(example[0])?;
+            let arguments = serde_json::from_str::<Vec<Vec<Value>>>(example[1])?;
+            let expected = outputs
+                .get(i)
+                .map_or(Ok(vec![]), |output| serde_json::from_str::<Vec<Value>>(output))?;
+
+            let calls = operations
+                .iter()
+                .zip(&arguments)
+                .enumerate()
+                .skip(1)
+                .map(|(j, (operation, args))| {
+                    let method = methods
+                        .iter()
+                        .find(|m| &m.name == operation)
+                        .ok_or_else(|| format!("Unknown method {operation} in examples"))?;
+
+                    Ok(Call {
+                        method: &method.name,
+                        args: process_args(&method.params, args),
+                        returns: &method.return_type,
+                        expected: expected
+                            .get(j)
+                            .filter(|v| !v.is_null())
+                            .map(|v| lang.process(&method.return_type, &v.to_string())),
+                    })
+                })
+                .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+
+            Ok((process_args(constructor, arguments.first().map_or(&[], Vec::as_slice)), calls))
+        })
+        .collect()
+}
+
+/// Renders `code` using the Jinja template for `lang`.
 fn render_problem(
-    config: &Config, template_name: &str, code: &Option<String>, metadata: &MetaData, examples: &str,
+    config: &Config, lang: &Lang, code: &Option<String>, metadata: &MetaData, examples: &str, outputs: &[String],
 ) -> Result<String, Box<dyn Error>> {
+    let template_name = &match metadata {
+        MetaData::Stdio => format!("stdio.{lang}.j2"),
+        _ => format!("sol.{lang}.j2"),
+    };
     let mut template = Tera::default();
     template.add_template_file(
         PathBuf::from(&config.project_dir_str).join(format!("runner/templates/{template_name}")),
@@ -63,6 +131,27 @@ fn render_problem(
             Some(c) => c.to_lowercase().collect::<String>() + it.as_str(),
         })?)
     });
+    template.register_filter("pascal", |value: &Value, _: &_| {
+        let s = tera::try_get_value!("pascal", "value", String, value);
+
+        let mut it = s.chars();
+        Ok(tera::to_value(match it.next() {
+            None => String::new(),
+            Some(c) => c.to_uppercase().collect::<String>() + it.as_str(),
+        })?)
+    });
+    template.register_filter("snake", |value: &Value, _: &_| {
+        let s = tera::try_get_value!("snake", "value", String, value);
+
+        Ok(tera::to_value(s.chars().fold(String::new(), |mut acc, c| {
+            if c.is_uppercase() {
+                acc.push('_');
+            }
+            acc.extend(c.to_lowercase());
+
+            acc
+        }))?)
+    });
     template.register_filter("process", |value: &Value, args: &HashMap<String, Value>| {
         let example = tera::try_get_value!("process", "value", String, value);
         let lang = match args.get("lang") {
@@ -77,35 +166,81 @@ fn render_problem(
         Ok(tera::to_value(lang.process(&typ, &example))?)
     });
 
+    // The data structures are defined only under the `leetcode` module of each library, whatever the problem source.
     let mut context = Context::new();
-    context.insert("datastructs", &Vec::<(Source, &str)>::from([]));
-    context.insert("code", code);
-    context.insert("function", &metadata.name);
-    context.insert("return", &metadata.return_type);
     context.insert(
-        "variables",
+        "datastructs",
         &metadata
-            .params
-            .iter()
-            .map(|v| (&v.name, &v.typ))
+            .datastructs()
+            .into_iter()
+            .map(|datastruct| (Source::LeetCode, datastruct))
             .collect::<Vec<_>>(),
     );
-    context.insert(
-        "examples",
-        &examples
-            .split_whitespace()
-            .collect::<Vec<&str>>()
-            .chunks_exact(metadata.params.len())
-            .collect::<Vec<_>>(),
-    );
-    context.insert("cleanup", &metadata.cleanup);
+    context.insert("code", code);
+    context.insert("cleanup", &metadata.cleanup());
+
+    match metadata {
+        MetaData::Function(function) => {
+            let (return_type, mutated) = function
+                .mutated()
+                .map_or((&function.return_type, None), |v| (&v.typ, Some(&v.name)));
+
+            context.insert("function", &function.name);
+            context.insert("return", return_type);
+            context.insert("mutated", &mutated);
+            context.insert(
+                "variables",
+                &function
+                    .params
+                    .iter()
+                    .map(|v| (&v.name, &v.typ))
+                    .collect::<Vec<_>>(),
+            );
+            context.insert(
+                "examples",
+                &examples
+                    .lines()
+                    .collect::<Vec<&str>>()
+                    .chunks_exact(function.params.len())
+                    .collect::<Vec<_>>(),
+            );
+            context.insert(
+                "outputs",
+                &outputs
+                    .iter()
+                    .map(|output| lang.process(return_type, output))
+                    .collect::<Vec<_>>(),
+            );
+        }
+        MetaData::Class { name, constructor, methods } => {
+            context.insert("classname", name);
+            context.insert("examples", &replay(lang, constructor, methods, examples, outputs)?);
+        }
+        MetaData::Stdio => (),
+    }
 
     Ok(template.render(template_name, &context)?)
 }
 
+/// Writes the sample inputs, listed as a JSON array in `examples`, and their expected `outputs` into `samples_dir`.
+fn write_samples(samples_dir: &Path, examples: &str, outputs: &[String]) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(samples_dir)?;
+
+    for (i, (input, output)) in serde_json::from_str::<Vec<String>>(examples)?
+        .iter()
+        .zip(outputs)
+        .enumerate()
+    {
+        fs::write(samples_dir.join(format!("{}.in", i + 1)), input)?;
+        fs::write(samples_dir.join(format!("{}.out", i + 1)), output)?;
+    }
+
+    Ok(())
+}
+
 /// Fetches and renders the question data into a solution file, of which its [`PathBuf`] is returned if successful.
 pub fn fetch(
-    id: &str, lang: &Lang, source: &Source, config: &Config, overwrite: bool,
+    id: &str, lang: &Lang, source: &Source, config: &Config, overwrite: bool, cache: &Cache,
 ) -> Result<(PathBuf, PathBuf), Box<dyn Error>> {
     let dirpath = PathBuf::from(&config.sol_dir_str)
         .join(source.to_string())
@@ -117,7 +252,7 @@ pub fn fetch(
     let sol_file_already_exists = sol_file.exists();
 
     if overwrite || !sol_file_already_exists {
-        let (desc, code, metadata, examples) = source.query(id, lang)?;
+        let (desc, code, metadata, examples, outputs) = source.query(id, lang, config, cache)?;
 
         if overwrite || !desc_file.exists() {
             print!("Rendering {}... ", desc_file.display().to_string().orange().bold());
@@ -130,16 +265,24 @@ pub fn fetch(
         print!("Rendering {}... ", sol_file.display().to_string().orange().bold());
         io::stdout().flush()?;
 
-        let template_name = format!("{}.j2", &file);
-        fs::write(&sol_file, render_problem(config, &template_name, &code, &metadata, &examples)?)?;
+        fs::write(&sol_file, render_problem(config, lang, &code, &metadata, &examples, &outputs)?)?;
         println!("{}!", "OK".green().bold());
 
-        if !sol_file_already_exists && lang == &Lang::Rust {
+        if let MetaData::Stdio = metadata {
+            let samples_dir = dirpath.join("samples");
+
+            print!("Writing samples into {}... ", samples_dir.display().to_string().orange().bold());
+            io::stdout().flush()?;
+
+            write_samples(&samples_dir, &examples, &outputs)?;
+            println!("{}!", "OK".green().bold());
+        }
+
+        if !sol_file_already_exists && matches!(lang, Lang::Go | Lang::Java | Lang::Rust) {
             println!(
-                "Updating {}:",
-                format!("{}/rust-project.json", &config.sol_dir_str)
-                    .orange()
-                    .bold()
+                "Updating {} dev environment at solution root {}:",
+                lang.get_name().cyan().bold(),
+                config.sol_dir_str.orange().bold()
             );
 
             lang.generate_setup(config)
@@ -157,3 +300,130 @@ pub fn fetch(
     );
     Ok((sol_file, desc_file))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::*;
+
+    /// Returns a [`Config`] rendering with the templates of the repository, and reading local problems from the
+    /// fixtures.
+    fn fixture_config() -> Config {
+        let runner_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let mut config = Config::new(runner_dir.join("..").display().to_string(), String::from("."));
+        config
+            .source
+            .insert(Source::Local.to_string(), json!({ "dir": runner_dir.join("tests/fixtures/local") }));
+
+        config
+    }
+
+    #[test]
+    fn local_problem_imports_datastructs_from_leetcode_library() {
+        let config = fixture_config();
+        let cache = Cache::new(&config, false, true);
+
+        for (lang, import) in [
+            (Lang::Cpp, "#include <leetcode/treenode.h>"),
+            (Lang::Go, "\"proctor/leetcode\""),
+            (Lang::Java, "import leetcode.TreeNode;"),
+            (Lang::Python, "from leetcode.treenode import TreeNode, treeNodeFrom"),
+            (Lang::Rust, "use libproctor::leetcode::TreeNode;"),
+            (Lang::TypeScript, "from \"proctor/leetcode/treenode\""),
+        ] {
+            let (_, code, metadata, examples, outputs) = Source::Local
+                .query("maximum-depth-of-binary-tree", &lang, &config, &cache)
+                .unwrap();
+            let rendered = render_problem(&config, &lang, &code, &metadata, &examples, &outputs).unwrap();
+
+            assert!(rendered.contains(import), "{lang} solution lacks `{import}`:\n{rendered}");
+            assert!(!rendered.contains("local"), "{lang} solution refers to the local source:\n{rendered}");
+        }
+    }
+
+    #[test]
+    fn double_results_are_compared_approximately() {
+        let config = fixture_config();
+        let cache = Cache::new(&config, false, true);
+
+        for (lang, comparison) in [
+            (Lang::Cpp, "CHECK_MESSAGE(abs(expectedOutput - output) <= 1e-5"),
+            (Lang::Go, "math.Abs("),
+            (Lang::Java, "checkApprox("),
+            (Lang::Python, "self.assertAlmostEqual("),
+            (Lang::Rust, "assert_approx_eq("),
+            (Lang::TypeScript, "approxEqual("),
+        ] {
+            for id in [
+                "average-salary-excluding-the-minimum-and-maximum-salary",
+                "find-median-from-data-stream",
+            ] {
+                let (_, code, metadata, examples, outputs) = Source::Local.query(id, &lang, &config, &cache).unwrap();
+                let rendered = render_problem(&config, &lang, &code, &metadata, &examples, &outputs).unwrap();
+
+                assert!(rendered.contains(comparison), "{lang} solution to {id} lacks `{comparison}`:\n{rendered}");
+            }
+        }
+    }
+
+    #[test]
+    fn cpp_pointers_are_compared_printed_and_freed_null_safely() {
+        let config = fixture_config();
+        let cache = Cache::new(&config, false, true);
+
+        let (_, code, metadata, examples, outputs) = Source::Local
+            .query("invert-binary-tree", &Lang::Cpp, &config, &cache)
+            .unwrap();
+        let rendered = render_problem(&config, &Lang::Cpp, &code, &metadata, &examples, &outputs).unwrap();
+        let tests = rendered.split_once("<<<<<").unwrap().1;
+
+        assert!(
+            tests.contains("TreeNode *root = treeNodeFrom({  });"),
+            "Example with an empty tree is missing:\n{tests}"
+        );
+        assert!(
+            tests.contains("CHECK_MESSAGE(equals(expectedOutput, output)"),
+            "Trees aren't compared null-safely:\n{tests}"
+        );
+        assert!(!tests.contains("<< *") && !tests.contains("CHECK(*"), "Trees are dereferenced:\n{tests}");
+        // The solution may return its input tree, so both are freed by one call, which frees each of their nodes once.
+        assert_eq!(
+            tests
+                .matches("destroy(expectedOutput); destroy({ root, output });")
+                .count(),
+            3,
+            "Trees aren't freed exactly once:\n{tests}"
+        );
+        assert!(
+            !tests.contains("destroy(root);") && !tests.contains("destroy(output);"),
+            "Trees are freed separately:\n{tests}"
+        );
+    }
+
+    #[test]
+    fn cpp_pointers_of_design_calls_are_freed_with_the_object() {
+        let config = fixture_config();
+        let cache = Cache::new(&config, false, true);
+
+        let (_, code, metadata, examples, outputs) = Source::Local
+            .query("complete-binary-tree-inserter", &Lang::Cpp, &config, &cache)
+            .unwrap();
+        let rendered = render_problem(&config, &Lang::Cpp, &code, &metadata, &examples, &outputs).unwrap();
+        let tests = rendered.split_once("<<<<<").unwrap().1;
+
+        // `get_root` returns the tree the object holds, so it's only freed once the object is done with it.
+        assert!(
+            tests.contains("vector<TreeNode*> ownedTreeNodes({ root });"),
+            "Constructor arguments aren't owned:\n{tests}"
+        );
+        assert!(
+            tests.contains("destroy(expectedOutput); ownedTreeNodes.push_back(output);\n    }"),
+            "Call results aren't freed:\n{tests}"
+        );
+        assert!(tests.ends_with("    destroy(ownedTreeNodes);\n}\n"), "Owned trees aren't freed:\n{tests}");
+        assert!(!tests.contains("destroy(root);"), "Trees are freed separately:\n{tests}");
+    }
+}
@@ -1,4 +1,3 @@
-use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::Display;
 use std::marker::PhantomData;
@@ -8,6 +7,7 @@ use reqwest::blocking::Client;
 use reqwest::StatusCode;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Deserializer};
+use serde_json::{json, Value};
 
 /// HTTP request methods.
 #[allow(clippy::upper_case_acronyms, dead_code)]
@@ -22,33 +22,41 @@ pub struct Request<V, T> {
     method: Method,
     pub query: &'static str,
     pub variable: V,
+    headers: Vec<(&'static str, String)>,
     response_type: PhantomData<T>,
 }
 
 impl<V: Display, T> Request<V, T> {
     /// Returns the [`Request`] formed from the given parameters.
     pub fn from(url: String, method: Method, query: &'static str, variable: V) -> Self {
-        Request { url, method, query, variable, response_type: PhantomData }
+        Request { url, method, query, variable, headers: vec![], response_type: PhantomData }
+    }
+
+    /// Returns the [`Request`] with the additional header `name` of value `value`, e.g. a session cookie.
+    pub fn header(mut self, name: &'static str, value: String) -> Self {
+        self.headers.push((name, value));
+        self
     }
 }
 
 /// A trait to allow construction of request body.
 trait Constructible {
     /// Returns a JSON request body.
-    fn json(&self) -> HashMap<&str, String>;
+    fn json(&self) -> Value;
 }
 
 impl<V: Display, T> Constructible for Request<V, T> {
-    fn json(&self) -> HashMap<&str, String> {
-        let mut json = HashMap::new();
+    fn json(&self) -> Value {
+        let variable = self.variable.to_string();
 
-        if !self.query.is_empty() {
-            json.insert("query", String::from(self.query));
+        if self.query.is_empty() {
+            // A request without a GraphQL query is a plain REST one, whose variable is the JSON request body itself
+            return serde_json::from_str(&variable).unwrap_or_else(|_| json!({}));
+        }
 
-            let variable = self.variable.to_string();
-            if !variable.is_empty() {
-                json.insert("variables", variable);
-            }
+        let mut json = json!({ "query": self.query });
+        if !variable.is_empty() {
+            json["variables"] = Value::String(variable);
         }
 
         json
@@ -66,13 +74,18 @@ where
     Self: Constructible,
 {
     fn response(&self, client: &Client) -> Result<T, Box<dyn Error>> {
-        match match self.method {
+        let builder = match self.method {
             Method::GET => client.get(self.url.to_string()),
             Method::POST => client.post(self.url.to_string()),
         }
-        .header("User-Agent", "rust")
-        .json(&self.json())
-        .send()
+        .header("User-Agent", "rust");
+
+        match self
+            .headers
+            .iter()
+            .fold(builder, |builder, (name, value)| builder.header(*name, value))
+            .json(&self.json())
+            .send()
         {
             Ok(response) => match response.status() {
                 StatusCode::OK => Ok(response.json::<T>()?),
@@ -0,0 +1,90 @@
+use std::io::{self, Write};
+use std::time::Duration;
+
+use colored::Colorize;
+
+use crate::modules::config::Config;
+use crate::modules::lang::Lang;
+use crate::modules::source::Source;
+
+use super::builder::Builder;
+use super::solution::{Failure, Limits, Solution, Usage};
+
+/// Compiles the solution with optimizations, runs it `runs` times holding each run to `limits`, and prints the
+/// statistics of the wall and CPU times of the runs.
+pub fn bench(id: &str, lang: &Lang, source: &Source, config: &Config, limits: Limits, runs: usize) {
+    let mut builder = Builder::new(lang, source, config, true);
+    let mut solution = Solution::new(id, lang, source, config, limits, true);
+
+    print!("Compiling optimized solution to problem {}... ", solution.id().blue());
+    io::stdout().flush().unwrap();
+
+    if let Err(compile_os) = builder.compile(&solution) {
+        println!("{}!\n\n{}:\n{}", "ERROR".red().bold(), "COMPILE STDERR".yellow().bold(), compile_os.stderr());
+        return;
+    }
+    println!("{}!", "SUCCESS".green().bold());
+
+    print!("Timing {runs} runs of solution to problem {}... ", solution.id().blue());
+    io::stdout().flush().unwrap();
+
+    let mut usages = vec![];
+    for run in 1..=runs {
+        if let Err(failure) = solution.run() {
+            let (verdict, run_os) = match failure {
+                Failure::Failed(run_os) => ("FAILED", run_os),
+                Failure::TimedOut(run_os) => ("TIME LIMIT EXCEEDED", run_os),
+                Failure::OutOfMemory(run_os) => ("MEMORY LIMIT EXCEEDED", run_os),
+            };
+            println!("Solution {} on run {run}, only passing solutions are benchmarked!\n", verdict.red().bold());
+            println!("\n{}:\n{}", "TEST STDOUT".yellow().bold(), run_os.stdout());
+            println!("\n{}:\n{}", "TEST STDERR".yellow().bold(), run_os.stderr());
+            return;
+        }
+        usages.extend(solution.usage());
+    }
+    println!("{}!", "DONE".green().bold());
+
+    print_stats(&usages);
+}
+
+/// Prints the min, median and 95th percentile of the wall and CPU times of `usages`, and their peak memory.
+fn print_stats(usages: &[Usage]) {
+    let ms = |d: Duration| format!("{:.2}ms", d.as_secs_f64() * 1000.0);
+    let row = |name: &str, times: Vec<Duration>| {
+        let mut times = times;
+        times.sort();
+
+        println!(
+            "{name:<6}{:>12}{:>12}{:>12}",
+            ms(percentile(&times, 0)),
+            ms(percentile(&times, 50)),
+            ms(percentile(&times, 95))
+        );
+    };
+
+    println!("\n{} ({} runs):", "BENCHMARK".yellow().bold(), usages.len());
+    println!("{:<6}{:>12}{:>12}{:>12}", "", "min", "median", "p95");
+    row("Wall", usages.iter().map(|usage| usage.wall_time).collect());
+    row("CPU", usages.iter().map(|usage| usage.cpu_time).collect());
+
+    let peak_memory = usages
+        .iter()
+        .map(|usage| usage.peak_memory)
+        .max()
+        .unwrap_or_default();
+    println!(
+        "\n{}: {}",
+        "PEAK MEMORY".yellow().bold(),
+        format!("{:.2}MB", peak_memory as f64 / 1024.0)
+            .cyan()
+            .bold()
+    );
+}
+
+/// Returns the `p`th percentile of the non-empty `sorted` times by the nearest-rank method.
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    let rank = (p * sorted.len()).div_ceil(100).max(1);
+
+    sorted[rank - 1]
+}
@@ -4,6 +4,7 @@ use std::process::Command;
 
 use crate::modules::config::Config;
 use crate::modules::lang::Lang;
+use crate::modules::source::Source;
 
 use super::output_streams::OutputStream;
 use super::solution::Solution;
@@ -16,9 +17,10 @@ pub struct Builder {
 }
 
 impl Builder {
-    /// Constructs a [`Builder`] for the language `lang`.
-    pub fn new(lang: &Lang, config: &Config) -> Self {
-        let compiler = lang.compiler(config);
+    /// Constructs a [`Builder`] for the language `lang`, building for problems from `source`, with optimizations for
+    /// timing instead of checks for debugging if `optimized`.
+    pub fn new(lang: &Lang, source: &Source, config: &Config, optimized: bool) -> Self {
+        let compiler = lang.compiler(config, source.stdio(), optimized);
 
         Builder { lang: lang.clone(), compiler, binfile: config.binfile(&lang.to_string()) }
     }
@@ -1,6 +1,10 @@
+mod bench;
 mod builder;
+mod outcome;
 mod output_streams;
+mod report;
 mod solution;
+mod status;
 
 use std::io::{self, Write};
 
@@ -11,16 +15,37 @@ use super::lang::Lang;
 use super::source::Source;
 
 use self::builder::Builder;
-use self::solution::Solution;
+use self::output_streams::OutputStream;
+use self::report::TestReport;
+use self::solution::{Failure, Solution};
 
-/// Compiles and tests the solution.
-pub fn run(id: &str, lang: &Lang, source: &Source, config: &Config) {
-    let mut builder = Builder::new(lang, config);
-    let mut solution = Solution::new(id, lang, source, config);
+pub use self::bench::bench;
+pub use self::outcome::{Format, Outcome};
+pub use self::solution::Limits;
+pub use self::status::Status;
+
+/// Compiles and tests the solution, holding each of its runs to `limits`, and returns the outcome of doing so.
+pub fn run(id: &str, lang: &Lang, source: &Source, config: &Config, limits: Limits) -> Outcome {
+    let mut builder = Builder::new(lang, source, config, false);
+    let mut solution = Solution::new(id, lang, source, config, limits, false);
 
     print!("Compiling solution to problem {}... ", solution.id().blue());
     io::stdout().flush().unwrap();
 
+    let mut outcome = Outcome {
+        id: id.to_string(),
+        lang: lang.to_string(),
+        source: source.to_string(),
+        compiled: false,
+        passed: false,
+        timed_out: false,
+        out_of_memory: false,
+        peak_memory: None,
+        compile: OutputStream::new(String::new(), String::new()),
+        test: None,
+        cases: vec![],
+    };
+
     match builder.compile(&solution) {
         Ok(compile_os) => {
             println!("{}!", "SUCCESS".green().bold());
@@ -31,24 +56,89 @@ pub fn run(id: &str, lang: &Lang, source: &Source, config: &Config) {
             print!("Testing solution to problem {}... ", solution.id().blue());
             io::stdout().flush().unwrap();
 
-            match solution.run() {
+            let (status, run_os, report, (timed_out, out_of_memory)) = match solution.run() {
                 Ok(run_os) => {
-                    println!(
-                        "Solution {}!\n\n{}:\n{}",
-                        "PASSED".green().bold(),
-                        "TEST RESULT".yellow().bold(),
-                        run_os.stdout_else_stderr(),
-                    );
+                    println!("Solution {}!", "PASSED".green().bold());
+
+                    let report = TestReport::parse(&run_os, lang, source.stdio());
+                    if report.cases.is_empty() {
+                        println!("\n{}:\n{}", "TEST RESULT".yellow().bold(), run_os.stdout_else_stderr());
+                    } else {
+                        println!("\n{}:", "TEST REPORT".yellow().bold());
+                        report.print();
+                    }
+
+                    (Status::Passed, run_os, report, (false, false))
                 }
-                Err(run_os) => {
-                    println!("Solution {}!\n", "FAILED".red().bold());
+                Err(failure) => {
+                    let (run_os, cut_short) = match failure {
+                        Failure::Failed(run_os) => {
+                            println!("Solution {}!\n", "FAILED".red().bold());
+                            (run_os, (false, false))
+                        }
+                        Failure::TimedOut(run_os) => {
+                            println!("Solution {} after {}s!\n", "TIME LIMIT EXCEEDED".red().bold(), limits.time.as_secs_f64());
+                            (run_os, (true, false))
+                        }
+                        Failure::OutOfMemory(run_os) => {
+                            println!(
+                                "Solution {} at {}MB!\n",
+                                "MEMORY LIMIT EXCEEDED".red().bold(),
+                                limits.memory.unwrap_or_default()
+                            );
+                            (run_os, (false, true))
+                        }
+                    };
                     println!("\n{}:\n{}", "TEST STDOUT".yellow().bold(), run_os.stdout());
                     println!("\n{}:\n{}", "TEST STDERR".yellow().bold(), run_os.stderr());
+
+                    // A run cut short leaves the report without the case it was cut short in, so only failed cases tell.
+                    let report = TestReport::parse(&run_os, lang, source.stdio());
+                    if report.passed() < report.cases.len() || (cut_short == (false, false) && !report.cases.is_empty()) {
+                        println!("\n{}:", "TEST REPORT".yellow().bold());
+                        report.print();
+                    }
+
+                    (Status::Failed, run_os, report, cut_short)
                 }
+            };
+
+            if let Some(usage) = solution.usage() {
+                println!(
+                    "\n{}: {}",
+                    "PEAK MEMORY".yellow().bold(),
+                    format!("{:.2}MB", usage.peak_memory as f64 / 1024.0)
+                        .cyan()
+                        .bold()
+                );
             }
+
+            record(status, id, source, config);
+
+            outcome.compiled = true;
+            outcome.compile = compile_os.strip_ansi();
+            outcome.passed = status == Status::Passed;
+            outcome.timed_out = timed_out;
+            outcome.out_of_memory = out_of_memory;
+            outcome.peak_memory = solution.usage().map(|usage| usage.peak_memory);
+            outcome.test = Some(run_os.strip_ansi());
+            outcome.cases = report.cases;
         }
         Err(compile_os) => {
             println!("{}!\n\n{}:\n{}", "ERROR".red().bold(), "COMPILE STDERR".yellow().bold(), compile_os.stderr());
+
+            record(Status::Failed, id, source, config);
+
+            outcome.compile = compile_os.strip_ansi();
         }
     }
+
+    outcome
+}
+
+/// Records `status` as the latest one of problem `id` of `source`, warning if it can't be recorded.
+fn record(status: Status, id: &str, source: &Source, config: &Config) {
+    if let Err(err) = status.record(id, source, config) {
+        println!("{}: Can't record status of problem {}: {err}", "WARNING".yellow().bold(), id.blue());
+    }
 }
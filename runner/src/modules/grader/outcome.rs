@@ -0,0 +1,126 @@
+use serde::Serialize;
+use strum::{Display, EnumString};
+
+use super::output_streams::OutputStream;
+use super::report::TestCase;
+
+/// Formats in which to emit the [`Outcome`] of compiling and testing a solution.
+#[derive(Clone, Debug, Default, Display, EnumString, PartialEq)]
+pub enum Format {
+    /// Colored text for humans, as printed while compiling and testing
+    #[default]
+    #[strum(serialize = "text")]
+    Text,
+    #[strum(serialize = "json")]
+    Json,
+    #[strum(serialize = "junit")]
+    Junit,
+}
+
+/// The outcome of compiling and testing a solution, with the captured streams stripped of ANSI escape codes.
+#[derive(Debug, Serialize)]
+pub struct Outcome {
+    pub id: String,
+    pub lang: String,
+    pub source: String,
+    pub compiled: bool,
+    pub passed: bool,
+    /// Whether a run of the solution was killed for exceeding its time limit.
+    pub timed_out: bool,
+    /// Whether a run of the solution failed to allocate memory within its memory limit.
+    pub out_of_memory: bool,
+    /// The peak resident set size in KB of the runs of the solution.
+    pub peak_memory: Option<u64>,
+    pub compile: OutputStream,
+    pub test: Option<OutputStream>,
+    pub cases: Vec<TestCase>,
+}
+
+impl Outcome {
+    /// Returns the outcome rendered in `format`, or [`None`] for [`Format::Text`], which is printed as it goes.
+    pub fn render(&self, format: &Format) -> Option<String> {
+        match format {
+            Format::Text => None,
+            Format::Json => serde_json::to_string_pretty(self).ok(),
+            Format::Junit => Some(self.junit()),
+        }
+    }
+
+    /// Returns the outcome as a JUnit XML document, with a test suite for the solution, in which a compile error, or a
+    /// failed or timed out run without any parsed failed test case, is reported as an erroneous test case.
+    fn junit(&self) -> String {
+        let suite = format!("{}.{}.{}", self.source, self.id, self.lang);
+        let mut cases = self
+            .cases
+            .iter()
+            .map(|case| {
+                let time = case
+                    .duration
+                    .map_or_else(String::new, |d| format!(" time=\"{:.6}\"", d.as_secs_f64()));
+                let failure = case.message.as_ref().map_or_else(String::new, |message| {
+                    let summary = message.lines().next().unwrap_or_default();
+                    format!("\n      <failure message=\"{}\">{}</failure>\n    ", escape(summary), escape(message))
+                });
+
+                format!("    <testcase name=\"{}\" classname=\"{suite}\"{time}>{failure}</testcase>\n", escape(&case.name))
+            })
+            .collect::<String>();
+
+        let failures = self.cases.iter().filter(|case| !case.passed).count();
+        let error = if !self.compiled {
+            Some(("Compile", "Compile error", self.compile.stderr()))
+        } else if !self.passed && failures == 0 {
+            let stderr = self.test.as_ref().map_or("", OutputStream::stderr);
+            let message = if self.timed_out {
+                "Time limit exceeded"
+            } else if self.out_of_memory {
+                "Memory limit exceeded"
+            } else {
+                "Run failed"
+            };
+            Some(("Run", message, stderr))
+        } else {
+            None
+        };
+        if let Some((name, message, details)) = error {
+            cases.push_str(&format!(
+                "    <testcase name=\"{name}\" classname=\"{suite}\">\n      <error message=\"{message}\">{}</error>\n    </testcase>\n",
+                escape(details)
+            ));
+        }
+        let errors = usize::from(error.is_some());
+        let tests = self.cases.len() + errors;
+        let time = self
+            .cases
+            .iter()
+            .filter_map(|case| case.duration)
+            .sum::<std::time::Duration>()
+            .as_secs_f64();
+        let (stdout, stderr) = self
+            .test
+            .as_ref()
+            .map_or(("", ""), |test| (test.stdout(), test.stderr()));
+
+        let properties = self.peak_memory.map_or_else(String::new, |peak_memory| {
+            format!("    <properties>\n      <property name=\"peak_memory_kb\" value=\"{peak_memory}\"/>\n    </properties>\n")
+        });
+
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <testsuites tests=\"{tests}\" failures=\"{failures}\" errors=\"{errors}\" time=\"{time:.6}\">\n  \
+             <testsuite name=\"{suite}\" tests=\"{tests}\" failures=\"{failures}\" errors=\"{errors}\" time=\"{time:.6}\">\n\
+             {properties}{cases}    <system-out>{}</system-out>\n    <system-err>{}</system-err>\n  </testsuite>\n</testsuites>\n",
+            escape(stdout),
+            escape(stderr)
+        )
+    }
+}
+
+/// Returns `s` with the characters special to XML escaped.
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
@@ -1,12 +1,21 @@
 use std::process::Output;
 
+use regex::Regex;
+use serde::Serialize;
+
 /// A wrapper around [`Output`] for easy access to the standard streams of a command.
+#[derive(Debug, Serialize)]
 pub struct OutputStream {
     stdout: String,
     stderr: String,
 }
 
 impl OutputStream {
+    /// Returns the [`OutputStream`] of the given streams.
+    pub fn new(stdout: String, stderr: String) -> Self {
+        OutputStream { stdout, stderr }
+    }
+
     /// Returns the [`OutputStream`] extracted from `output`.
     pub fn from(output: &Output) -> Self {
         OutputStream {
@@ -15,6 +24,16 @@ impl OutputStream {
         }
     }
 
+    /// Returns the [`OutputStream`] with the ANSI escape codes coloring its streams stripped.
+    pub fn strip_ansi(&self) -> Self {
+        let ansi = Regex::new(r"\x1b\[[0-9;]*m|\x1b\(B").expect("Invalid ANSI escape regex! Error");
+
+        OutputStream {
+            stdout: ansi.replace_all(&self.stdout, "").to_string(),
+            stderr: ansi.replace_all(&self.stderr, "").to_string(),
+        }
+    }
+
     /// Returns the stdout stream.
     pub fn stdout(&self) -> &str {
         self.stdout.as_str()
@@ -27,6 +46,10 @@ impl OutputStream {
 
     /// Returns either the non-empty stdout stream, else the stderr stream.
     pub fn stdout_else_stderr(&self) -> &str {
-        if self.stdout.is_empty() { self.stderr() } else { self.stdout() }
+        if self.stdout.is_empty() {
+            self.stderr()
+        } else {
+            self.stdout()
+        }
     }
 }
@@ -0,0 +1,781 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use colored::Colorize;
+use regex::Regex;
+use serde::{Serialize, Serializer};
+
+use crate::modules::lang::Lang;
+
+use super::output_streams::OutputStream;
+
+/// The result of a single test case of a solution.
+#[derive(Debug, Serialize)]
+pub struct TestCase {
+    pub name: String,
+    pub passed: bool,
+    /// What the failed assertion of the case was about, e.g. `expected [1, 2], got [2, 1]`, followed by its context.
+    pub message: Option<String>,
+    /// The duration of the case, serialized in seconds.
+    #[serde(serialize_with = "as_secs")]
+    pub duration: Option<Duration>,
+}
+
+/// The results of the test cases of a solution, parsed from the output of whichever test framework ran them.
+#[derive(Debug, Default)]
+pub struct TestReport {
+    pub cases: Vec<TestCase>,
+}
+
+impl TestReport {
+    /// Returns the [`TestReport`] parsed from `output`, as printed by the test framework of language `lang`, or by
+    /// the sample judge if `judged`.
+    pub fn parse(output: &OutputStream, lang: &Lang, judged: bool) -> Self {
+        let output = output.strip_ansi();
+        let text = format!("{}\n{}", output.stdout(), output.stderr());
+
+        let cases = if judged {
+            libtest(&text)
+        } else {
+            match lang {
+                Lang::Cpp => doctest(&text),
+                Lang::Go => gotest(&text),
+                Lang::Java | Lang::Rust => libtest(&text),
+                Lang::Python => unittest(&text),
+                Lang::TypeScript => node(&text),
+            }
+        };
+
+        TestReport {
+            cases: cases
+                .into_iter()
+                .map(|(name, passed, details, duration)| TestCase {
+                    name: display_name(&name),
+                    passed,
+                    message: (!passed).then(|| message(&details)),
+                    duration,
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the number of passed test cases.
+    pub fn passed(&self) -> usize {
+        self.cases.iter().filter(|case| case.passed).count()
+    }
+
+    /// Returns the one-line summary of the report, e.g. `2/3 cases passed, Case 2 failed: expected [1,2] got [2,1]`.
+    pub fn summary(&self) -> String {
+        self.cases.iter().filter(|case| !case.passed).fold(
+            format!("{}/{} cases passed", self.passed(), self.cases.len()),
+            |summary, case| {
+                let reason = case
+                    .message
+                    .as_deref()
+                    .and_then(|m| m.lines().next())
+                    .unwrap_or("");
+                format!("{summary}, {} failed: {reason}", case.name)
+            },
+        )
+    }
+
+    /// Prints the report as a table of the test cases, followed by its summary.
+    pub fn print(&self) {
+        let width = self
+            .cases
+            .iter()
+            .map(|case| case.name.len())
+            .max()
+            .unwrap_or(0);
+
+        for case in &self.cases {
+            let duration = case
+                .duration
+                .map_or_else(String::new, |d| format!("{:.2}ms", d.as_secs_f64() * 1000.0));
+            println!(
+                "  {}  {}",
+                if case.passed { format!("{:<6}", "ok").green().bold() } else { "FAILED".red().bold() },
+                format!("{:<width$}  {duration}", case.name).trim_end()
+            );
+            for line in case.message.iter().flat_map(|m| m.lines()) {
+                println!("  {:<6}  {line}", "");
+            }
+        }
+
+        let summary = self.summary();
+        println!("\n{}", if self.passed() == self.cases.len() { summary.green() } else { summary.red() });
+    }
+}
+
+/// A test case as parsed from the output of a test framework, with its raw name, whether it passed, the lines of
+/// output detailing it, and its duration if reported.
+type ParsedCase = (String, bool, Vec<String>, Option<Duration>);
+
+/// Returns the test cases in the output `text` of a libtest-style test runner, which reports a case as
+/// `test NAME ... ok` or `test NAME ... FAILED`, followed by its details, or by a `---- NAME stdout ----` section.
+fn libtest(text: &str) -> Vec<ParsedCase> {
+    let result = Regex::new(r"^test (?<name>\S+) \.\.\. (?<result>ok|FAILED)$").expect("Invalid libtest regex! Error");
+    let section = Regex::new(r"^---- (?<name>\S+) stdout ----$").expect("Invalid libtest regex! Error");
+
+    let (mut cases, mut details) = (vec![], HashMap::<String, Vec<String>>::new());
+    let mut current = None;
+    for line in text.lines() {
+        if let Some(caps) = result.captures(line) {
+            cases.push((caps["name"].to_string(), &caps["result"] == "ok"));
+            current = Some(caps["name"].to_string());
+        } else if let Some(caps) = section.captures(line) {
+            current = Some(caps["name"].to_string());
+        } else if ["successes:", "failures:", "running "]
+            .iter()
+            .any(|s| line.starts_with(s))
+            || line.starts_with("test result:")
+        {
+            current = None;
+        } else if let Some(name) = &current {
+            details
+                .entry(name.clone())
+                .or_default()
+                .push(line.to_string());
+        }
+    }
+
+    cases
+        .into_iter()
+        .map(|(name, passed)| {
+            let details = details.remove(&name).unwrap_or_default();
+            (name, passed, details, None)
+        })
+        .collect()
+}
+
+/// Returns the test cases in the output `text` of Python's `unittest` run verbosely.
+fn unittest(text: &str) -> Vec<ParsedCase> {
+    let result = Regex::new(r"^(?<name>\w+) \(.*\) \.\.\. (?<result>ok|FAIL|ERROR)$").expect("Invalid unittest regex! Error");
+    let section = Regex::new(r"^(?:FAIL|ERROR): (?<name>\w+) \(").expect("Invalid unittest regex! Error");
+
+    let (mut cases, mut details) = (vec![], HashMap::<String, Vec<String>>::new());
+    let mut current: Option<String> = None;
+    for line in text.lines() {
+        if let Some(caps) = result.captures(line) {
+            cases.push((caps["name"].to_string(), &caps["result"] == "ok"));
+        } else if let Some(caps) = section.captures(line) {
+            current = Some(caps["name"].to_string());
+        } else if line.starts_with("=====") || line.starts_with("Ran ") {
+            current = None;
+        } else if let Some(name) = &current {
+            let lines = details.entry(name.clone()).or_default();
+            if line.starts_with("-----") && !lines.is_empty() {
+                current = None;
+            } else if !line.starts_with("-----") {
+                lines.push(line.to_string());
+            }
+        }
+    }
+
+    cases
+        .into_iter()
+        .map(|(name, passed)| {
+            // Skip the traceback down to the raised exception
+            let details = details.remove(&name).unwrap_or_default();
+            let start = details
+                .iter()
+                .rposition(|l| l.starts_with("Traceback") || l.starts_with(' '))
+                .map_or(0, |pos| pos + 1);
+            (name, passed, details[start..].to_vec(), None)
+        })
+        .collect()
+}
+
+/// Returns the test cases in the output `text` of `go test -test.v`, excluding the parent tests of subtests.
+fn gotest(text: &str) -> Vec<ParsedCase> {
+    let run = Regex::new(r"^=== (?:RUN|PAUSE|CONT)\s+(?<name>\S+)$").expect("Invalid go test regex! Error");
+    let result =
+        Regex::new(r"^\s*--- (?<result>PASS|FAIL): (?<name>\S+) \((?<secs>[\d.]+)s\)$").expect("Invalid go test regex! Error");
+
+    let mut cases = Vec::<ParsedCase>::new();
+    let mut current = None;
+    for line in text.lines() {
+        if let Some(caps) = run.captures(line) {
+            if !cases.iter().any(|case| case.0 == caps["name"]) {
+                cases.push((caps["name"].to_string(), false, vec![], None));
+            }
+            current = cases.iter().position(|case| case.0 == caps["name"]);
+        } else if let Some(caps) = result.captures(line) {
+            if !cases.iter().any(|case| case.0 == caps["name"]) {
+                cases.push((caps["name"].to_string(), false, vec![], None));
+            }
+            current = cases.iter().position(|case| case.0 == caps["name"]);
+            if let Some(case) = current.map(|i| &mut cases[i]) {
+                case.1 = &caps["result"] == "PASS";
+                case.3 = caps["secs"].parse().ok().map(Duration::from_secs_f64);
+            }
+        } else if line.starts_with(char::is_whitespace) {
+            if let Some(case) = current.map(|i| &mut cases[i]) {
+                case.2.push(line.to_string());
+            }
+        } else {
+            current = None;
+        }
+    }
+
+    let names = cases.iter().map(|case| case.0.clone()).collect::<Vec<_>>();
+    cases
+        .into_iter()
+        .filter(|case| {
+            !names
+                .iter()
+                .any(|name| name.starts_with(&format!("{}/", case.0)))
+        })
+        .collect()
+}
+
+/// Returns the test cases in the output `text` of `node --test` with the `spec` reporter.
+fn node(text: &str) -> Vec<ParsedCase> {
+    let result = Regex::new(r"^(?<result>✔|✖) (?<name>.+?) \((?<ms>[\d.]+)ms\)$").expect("Invalid node test regex! Error");
+
+    let mut cases = Vec::<ParsedCase>::new();
+    for line in text.lines() {
+        // The summary, followed by a repeat of the failing cases
+        if line.starts_with('ℹ') {
+            break;
+        }
+
+        if let Some(caps) = result.captures(line) {
+            // A test file without any test in it is reported as a test of its own, named by its path
+            if caps["name"].starts_with('/') {
+                continue;
+            }
+
+            let duration = caps["ms"]
+                .parse::<f64>()
+                .ok()
+                .map(|ms| Duration::from_secs_f64(ms / 1000.0));
+            cases.push((caps["name"].to_string(), &caps["result"] == "✔", vec![], duration));
+        } else if let Some(case) = cases.last_mut().filter(|_| line.starts_with(' ')) {
+            case.2.push(line.to_string());
+        }
+    }
+
+    cases
+}
+
+/// Returns the test cases in the output `text` of a doctest binary run with `--success` and `--duration=true`.
+fn doctest(text: &str) -> Vec<ParsedCase> {
+    let header = Regex::new(r"^TEST CASE:\s+(?<name>.+)$").expect("Invalid doctest regex! Error");
+    let error = Regex::new(r": (?:FATAL )?ERROR: ").expect("Invalid doctest regex! Error");
+    let duration = Regex::new(r"^(?<secs>\d+\.\d+) s: (?<name>.+)$").expect("Invalid doctest regex! Error");
+
+    let mut cases = Vec::<ParsedCase>::new();
+    let position = |cases: &mut Vec<ParsedCase>, name: &str| {
+        cases
+            .iter()
+            .position(|case| case.0 == name)
+            .unwrap_or_else(|| {
+                cases.push((name.to_string(), true, vec![], None));
+                cases.len() - 1
+            })
+    };
+
+    let (mut current, mut in_error) = (None, false);
+    for line in text.lines() {
+        if let Some(caps) = header.captures(line) {
+            current = Some(position(&mut cases, caps["name"].trim()));
+        } else if let Some(caps) = duration.captures(line) {
+            let i = position(&mut cases, caps["name"].trim());
+            cases[i].3 = caps["secs"].parse().ok().map(Duration::from_secs_f64);
+        } else if let Some(i) = current.filter(|_| error.is_match(line)) {
+            cases[i].1 = false;
+            cases[i]
+                .2
+                .push(error.splitn(line, 2).nth(1).unwrap_or(line).to_string());
+            in_error = true;
+        } else if let Some(i) = current.filter(|_| in_error && line.starts_with(' ')) {
+            cases[i].2.push(line.to_string());
+        } else {
+            in_error = false;
+            if line.starts_with("=====") {
+                current = None;
+            }
+        }
+    }
+
+    cases
+}
+
+/// Returns the display name of the test case of raw name `name`, e.g. `Case 1` for `tests::case_1` or
+/// `TestSolution/case_1`.
+fn display_name(name: &str) -> String {
+    let name = name.rsplit(['/', ':']).next().unwrap_or(name);
+    let name = name.strip_prefix("test_").unwrap_or(name).replace('_', " ");
+
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect())
+}
+
+/// Returns the message of a failed test case from the lines of output `details` detailing it, which states the
+/// expected and actual results of its failed assertion if recognized, followed by the context of the assertion.
+fn message(details: &[String]) -> String {
+    let lines = details
+        .iter()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>();
+    let find = |pattern: &str| {
+        let re = Regex::new(pattern).expect("Invalid assertion regex! Error");
+        lines.iter().find_map(|l| {
+            re.captures(l)
+                .map(|caps| caps["value"].trim_end_matches(',').to_string())
+        })
+    };
+
+    let assertion = find(r"expected (?<value>.+?),? but got .+$")
+        .zip(find(r"expected .+?,? but got (?<value>.+)$"))
+        .or_else(|| find(r"^left: (?<value>.+)$").zip(find(r"^right: (?<value>.+)$")))
+        .or_else(|| find(r"^expected: (?<value>.+)$").zip(find(r"^actual: (?<value>.+)$")))
+        .or_else(|| find(r"Error: (?:\w+ differ: )?(?<value>.+?) != .+$").zip(find(r"Error: .+? != (?<value>.+?)(?:\s+:.*)?$")))
+        .or_else(|| find(r"CHECK\( (?<value>.+?) == .+ \)$").zip(find(r"CHECK\( .+? == (?<value>.+) \)$")))
+        .map(|(expected, got)| format!("expected {expected}, got {got}"))
+        .or_else(|| find(r"^(?<value>line \d+: expected .+, got .+)$"))
+        .or_else(|| {
+            lines
+                .iter()
+                .position(|l| l.contains("panicked at"))
+                .and_then(|i| lines.get(i + 1))
+                .or_else(|| lines.first())
+                .map(|l| l.to_string())
+        })
+        .unwrap_or_else(|| String::from("no details reported"));
+
+    let context = Regex::new(r"(?:Input|Call \d+): .*").expect("Invalid assertion context regex! Error");
+    lines
+        .iter()
+        .filter_map(|l| context.find(l))
+        .fold(assertion, |message, context| format!("{message}\n{}", context.as_str()))
+}
+
+/// Serializes `duration` as fractional seconds.
+fn as_secs<S: Serializer>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error> {
+    duration.map(|d| d.as_secs_f64()).serialize(serializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the test report parsed from `stdout` and `stderr` as printed when testing a solution in `lang`.
+    fn parse(stdout: &str, stderr: &str, lang: Lang) -> TestReport {
+        TestReport::parse(&OutputStream::new(stdout.to_string(), stderr.to_string()), &lang, false)
+    }
+
+    /// Returns the name, whether passed, and message of each case of `report`.
+    fn cases(report: &TestReport) -> Vec<(&str, bool, Option<&str>)> {
+        report
+            .cases
+            .iter()
+            .map(|case| (case.name.as_str(), case.passed, case.message.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn libtest_with_ansi_colors() {
+        let stdout = "
+running 3 tests
+test tests::case_1 ... \x1b[32mok\x1b(B\x1b[m
+test tests::case_2 ... \x1b[31mFAILED\x1b(B\x1b[m
+test tests::case_3 ... \x1b[32mok\x1b(B\x1b[m
+
+successes:
+
+---- tests::case_1 stdout ----
+debug
+
+---- tests::case_3 stdout ----
+debug
+
+
+successes:
+    tests::case_1
+    tests::case_3
+
+failures:
+
+---- tests::case_2 stdout ----
+debug
+
+thread 'tests::case_2' (32723) panicked at /tmp/pt/sol/leetcode/0001/sol.rs:37:9:
+assertion `left == right` failed: Input: nums = [3, 2, 4], target = 6
+  left: [1, 2]
+ right: [0, 1]
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+
+failures:
+    tests::case_2
+
+test result: \x1b[31mFAILED\x1b(B\x1b[m. 2 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.02s
+
+";
+        let report = parse(stdout, "", Lang::Rust);
+
+        assert_eq!(
+            cases(&report),
+            [
+                ("Case 1", true, None),
+                ("Case 2", false, Some("expected [1, 2], got [0, 1]\nInput: nums = [3, 2, 4], target = 6")),
+                ("Case 3", true, None),
+            ]
+        );
+        assert_eq!(report.summary(), "2/3 cases passed, Case 2 failed: expected [1, 2], got [0, 1]");
+    }
+
+    #[test]
+    fn libtest_panic() {
+        let stdout = "
+running 1 test
+test tests::case_1 ... FAILED
+
+failures:
+
+---- tests::case_1 stdout ----
+
+thread 'tests::case_1' (1) panicked at sol.rs:5:9:
+index out of bounds: the len is 3 but the index is 5
+note: run with `RUST_BACKTRACE=1`
+
+failures:
+    tests::case_1
+
+test result: FAILED. 0 passed; 1 failed
+";
+
+        assert_eq!(
+            cases(&parse(stdout, "", Lang::Rust)),
+            [("Case 1", false, Some("index out of bounds: the len is 3 but the index is 5"))]
+        );
+    }
+
+    #[test]
+    fn libtest_no_tests() {
+        let stdout = "
+running 0 tests
+
+test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+
+";
+        let report = parse(stdout, "", Lang::Rust);
+
+        assert!(report.cases.is_empty());
+        assert_eq!(report.summary(), "0/0 cases passed");
+    }
+
+    #[test]
+    fn libtest_style_java() {
+        let stdout = "test case_1 ... ok
+test case_2 ... FAILED
+java.lang.AssertionError: expected [1, 2] but got [0, 1]
+Input: nums = [3, 2, 4], target = 6
+test case_3 ... ok
+
+test result: FAILED. 2 passed; 1 failed
+";
+
+        assert_eq!(
+            cases(&parse(stdout, "", Lang::Java)),
+            [
+                ("Case 1", true, None),
+                ("Case 2", false, Some("expected [1, 2], got [0, 1]\nInput: nums = [3, 2, 4], target = 6")),
+                ("Case 3", true, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn libtest_style_judge() {
+        let stdout = "test sample_1 ... ok
+test sample_2 ... FAILED
+    line 2: expected `YES`, got `NO`
+    expected:
+        3
+        YES
+    got:
+        3
+        NO
+
+test result: FAILED. 1 passed; 1 failed
+";
+        let report = TestReport::parse(&OutputStream::new(stdout.to_string(), String::new()), &Lang::Cpp, true);
+
+        assert_eq!(
+            cases(&report),
+            [
+                ("Sample 1", true, None),
+                ("Sample 2", false, Some("line 2: expected `YES`, got `NO`"))
+            ]
+        );
+    }
+
+    #[test]
+    fn unittest_fail() {
+        let stderr = "test_case_1 (__main__.Test.test_case_1) ... ok
+test_case_2 (__main__.Test.test_case_2) ... FAIL
+test_case_3 (__main__.Test.test_case_3) ... ok
+
+======================================================================
+FAIL: test_case_2 (__main__.Test.test_case_2)
+----------------------------------------------------------------------
+Traceback (most recent call last):
+  File \"/tmp/pt/sol/leetcode/0001/sol.py\", line 56, in test_case_2
+    self.assertEqual(expectedOutput, output, f\"\\nInput: nums = {nums}, target = {target}\")
+AssertionError: Lists differ: [1, 2] != [0, 1]
+
+First differing element 0:
+1
+0
+
+- [1, 2]
++ [0, 1] : 
+Input: nums = [3, 2, 4], target = 6
+
+----------------------------------------------------------------------
+Ran 3 tests in 0.001s
+
+FAILED (failures=1)
+";
+
+        assert_eq!(
+            cases(&parse("", stderr, Lang::Python)),
+            [
+                ("Case 1", true, None),
+                ("Case 2", false, Some("expected [1, 2], got [0, 1]\nInput: nums = [3, 2, 4], target = 6")),
+                ("Case 3", true, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn unittest_error() {
+        let stderr = "test_case_1 (__main__.Test.test_case_1) ... ERROR
+test_case_2 (__main__.Test.test_case_2) ... FAIL
+
+======================================================================
+ERROR: test_case_1 (__main__.Test.test_case_1)
+----------------------------------------------------------------------
+Traceback (most recent call last):
+  File \"/tmp/sol.py\", line 56, in test_case_1
+    output = Solution().f(n)
+  File \"/tmp/sol.py\", line 10, in f
+    return 1 / 0
+ZeroDivisionError: division by zero
+
+======================================================================
+FAIL: test_case_2 (__main__.Test.test_case_2)
+----------------------------------------------------------------------
+Traceback (most recent call last):
+  File \"/tmp/sol.py\", line 60, in test_case_2
+    self.assertEqual(expectedOutput, output, f\"\\nInput: n = {n}\")
+AssertionError: 3 != 4 : 
+Input: n = 2
+
+----------------------------------------------------------------------
+Ran 2 tests in 0.001s
+
+FAILED (failures=1, errors=1)
+";
+
+        assert_eq!(
+            cases(&parse("", stderr, Lang::Python)),
+            [
+                ("Case 1", false, Some("ZeroDivisionError: division by zero")),
+                ("Case 2", false, Some("expected 3, got 4\nInput: n = 2")),
+            ]
+        );
+    }
+
+    #[test]
+    fn unittest_no_tests() {
+        let stderr = "
+----------------------------------------------------------------------
+Ran 0 tests in 0.000s
+
+OK
+";
+
+        assert!(parse("", stderr, Lang::Python).cases.is_empty());
+    }
+
+    #[test]
+    fn gotest_subtests() {
+        let stdout = "=== RUN   TestSolution
+=== RUN   TestSolution/case_1
+=== RUN   TestSolution/case_2
+    sol_test.go:79: expected [1 2] but got [0 1]
+        Input: nums = [3 2 4], target = 6
+=== RUN   TestSolution/case_3
+--- FAIL: TestSolution (0.00s)
+    --- PASS: TestSolution/case_1 (0.00s)
+    --- FAIL: TestSolution/case_2 (0.01s)
+    --- PASS: TestSolution/case_3 (0.00s)
+FAIL
+";
+        let report = parse(stdout, "", Lang::Go);
+
+        assert_eq!(
+            cases(&report),
+            [
+                ("Case 1", true, None),
+                ("Case 2", false, Some("expected [1 2], got [0 1]\nInput: nums = [3 2 4], target = 6")),
+                ("Case 3", true, None),
+            ]
+        );
+        assert_eq!(report.cases[1].duration, Some(Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn gotest_no_tests() {
+        let stdout = "testing: warning: no tests to run
+PASS
+";
+
+        assert!(parse(stdout, "", Lang::Go).cases.is_empty());
+    }
+
+    #[test]
+    fn node_fail_and_throw() {
+        let stdout = "debug
+\u{2714} case_1 (3.00998ms)
+\u{2716} case_2 (7.955594ms)
+  AssertionError [ERR_ASSERTION]: 
+  Input: nums = [3,2,4], target = 6
+  + actual - expected
+  
+    [
+  +   0,
+  +   1
+  -   1,
+  -   2
+    ]
+      at TestContext.<anonymous> (/tmp/pt/nt/sol.js:4:46)
+      at Test.runInAsyncScope (node:async_hooks:206:9)
+      at async Test.processPendingSubtests (node:internal/test_runner/test:526:7) {
+    generatedMessage: false,
+    code: 'ERR_ASSERTION',
+    actual: [ 0, 1 ],
+    expected: [ 1, 2 ],
+    operator: 'deepStrictEqual'
+  }
+
+\u{2716} case_3 (0.223897ms)
+  TypeError [Error]: boom
+      at TestContext.<anonymous> (/tmp/pt/nt/sol.js:5:30)
+      at async Test.processPendingSubtests (node:internal/test_runner/test:526:7)
+
+\u{2139} tests 3
+\u{2139} suites 0
+\u{2139} pass 1
+\u{2139} fail 2
+\u{2139} cancelled 0
+\u{2139} skipped 0
+\u{2139} todo 0
+\u{2139} duration_ms 211.930232
+
+\u{2716} failing tests:
+
+test at sol.js:4:1
+\u{2716} case_2 (7.955594ms)
+  AssertionError [ERR_ASSERTION]: 
+  Input: nums = [3,2,4], target = 6
+";
+        let report = parse(stdout, "", Lang::TypeScript);
+
+        assert_eq!(
+            cases(&report),
+            [
+                ("Case 1", true, None),
+                ("Case 2", false, Some("expected [ 1, 2 ], got [ 0, 1 ]\nInput: nums = [3,2,4], target = 6")),
+                ("Case 3", false, Some("TypeError [Error]: boom")),
+            ]
+        );
+        assert_eq!(report.cases[0].duration, Some(Duration::from_nanos(3_009_980)));
+    }
+
+    #[test]
+    fn node_no_tests() {
+        let stdout = "\u{2714} /tmp/rt/t.js (139.455456ms)
+\u{2139} tests 1
+\u{2139} suites 0
+\u{2139} pass 1
+\u{2139} fail 0
+\u{2139} cancelled 0
+\u{2139} skipped 0
+\u{2139} todo 0
+\u{2139} duration_ms 146.107831
+";
+
+        assert!(parse(stdout, "", Lang::TypeScript).cases.is_empty());
+    }
+
+    #[test]
+    fn doctest_fail() {
+        let stdout = "[doctest] doctest version is \"2.4.11\"
+[doctest] run with \"--help\" for options
+===============================================================================
+/tmp/sol.cpp:20:
+TEST CASE:  Case 1
+
+/tmp/sol.cpp:27: SUCCESS: CHECK( expectedOutput == output ) is correct!
+  values: CHECK( {0, 1} == {0, 1} )
+  logged: Input: nums = {2, 7, 11, 15}, target = 9
+
+0.000021 s: Case 1
+===============================================================================
+/tmp/sol.cpp:31:
+TEST CASE:  Case 2
+
+/tmp/sol.cpp:38: ERROR: CHECK( expectedOutput == output ) is NOT correct!
+  values: CHECK( {1, 2} == {0, 1} )
+  logged: Input: nums = {3, 2, 4}, target = 6
+
+0.000034 s: Case 2
+0.000011 s: Case 3
+===============================================================================
+[doctest] test cases: 3 | 2 passed | 1 failed | 0 skipped
+";
+        let report = parse(stdout, "", Lang::Cpp);
+
+        assert_eq!(
+            cases(&report),
+            [
+                ("Case 1", true, None),
+                ("Case 2", false, Some("expected {1, 2}, got {0, 1}\nInput: nums = {3, 2, 4}, target = 6")),
+                ("Case 3", true, None),
+            ]
+        );
+        assert_eq!(report.cases[1].duration, Some(Duration::from_micros(34)));
+    }
+
+    #[test]
+    fn doctest_no_tests() {
+        let stdout = "[doctest] doctest version is \"2.4.11\"
+[doctest] run with \"--help\" for options
+===============================================================================
+[doctest] test cases: 0 | 0 passed | 0 failed | 0 skipped
+[doctest] Status: SUCCESS!
+";
+
+        assert!(parse(stdout, "", Lang::Cpp).cases.is_empty());
+    }
+
+    #[test]
+    fn message_without_recognized_assertion() {
+        let details = [
+            String::new(),
+            String::from("  something went wrong  "),
+            String::from("Input: n = 1"),
+        ];
+
+        assert_eq!(message(&details), "something went wrong\nInput: n = 1");
+        assert_eq!(message(&[]), "no details reported");
+    }
+}
@@ -1,5 +1,15 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::mem;
+use std::os::unix::process::{CommandExt, ExitStatusExt};
 use std::path::PathBuf;
-use std::process::Command;
+use std::process::{Command, ExitStatus, Output, Stdio};
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Once;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use crate::modules::config::Config;
 use crate::modules::lang::Lang;
@@ -7,17 +17,85 @@ use crate::modules::source::Source;
 
 use super::output_streams::OutputStream;
 
+/// What the runtimes of the languages print when an allocation fails, e.g. for hitting the memory limit.
+const OUT_OF_MEMORY_MARKERS: &[&str] = &[
+    "memory allocation of",
+    "MemoryError",
+    "OutOfMemoryError",
+    "insufficient memory",
+    "std::bad_alloc",
+    "out of memory",
+    "hard rss limit exhausted",
+];
+
+/// The limits on each run of a solution.
+#[derive(Clone, Copy, Debug)]
+pub struct Limits {
+    pub time: Duration,
+    /// The limit on the address space in MB, if any, or on the resident set size for C++ built with AddressSanitizer.
+    pub memory: Option<u64>,
+}
+
+/// The resources used by a run of a solution, as reported by `wait4` for the child process it spawned, which only
+/// accounts for the descendants of that process it waited for; those orphaned or still running when it exited are left
+/// out.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Usage {
+    pub wall_time: Duration,
+    /// The user and system CPU time of the child process and its waited-for descendants.
+    pub cpu_time: Duration,
+    /// The peak resident set size in KB of the largest of the child process and its waited-for descendants.
+    pub peak_memory: u64,
+}
+
+/// The ways in which running a solution can go wrong.
+pub enum Failure {
+    /// The solution ran to completion, but failed its tests.
+    Failed(OutputStream),
+    /// The solution was killed for running longer than its time limit.
+    TimedOut(OutputStream),
+    /// The solution failed to allocate memory within its memory limit.
+    OutOfMemory(OutputStream),
+}
+
 /// A structure defining a solution to a coding problem.
 pub struct Solution {
     id: String,
     prob_dir: PathBuf,
     runner: Command,
+    stdio: bool,
+    limits: Limits,
+    usage: Option<Usage>,
 }
 
 impl Solution {
-    /// Constructs a [`Solution`] to the problem `id`.
-    pub fn new(id: &str, lang: &Lang, source: &Source, config: &Config) -> Self {
-        let runner = lang.tester(config);
+    /// Constructs a [`Solution`] to the problem `id`, each of whose runs is held to `limits`, built with optimizations
+    /// for timing instead of checks for debugging if `optimized`.
+    pub fn new(id: &str, lang: &Lang, source: &Source, config: &Config, limits: Limits, optimized: bool) -> Self {
+        let mut runner = if source.stdio() { lang.executor(config) } else { lang.tester(config) };
+        if let (Some(memory), Lang::Cpp, false) = (limits.memory, lang, optimized) {
+            // AddressSanitizer reserves terabytes of address space for its shadow memory on startup, so it's left to
+            // enforce the limit itself, on the resident set size and the size of each allocation.
+            let options = env::var("ASAN_OPTIONS").map_or_else(|_| String::new(), |options| format!("{options}:"));
+            runner.env(
+                "ASAN_OPTIONS",
+                format!("{options}hard_rss_limit_mb={memory}:max_allocation_size_mb={memory}:allocator_may_return_null=1"),
+            );
+        } else if let Some(memory) = limits.memory {
+            let bytes = memory.saturating_mul(1 << 20);
+            let limit = libc::rlimit { rlim_cur: bytes, rlim_max: bytes };
+
+            // SAFETY: The hook only calls `setrlimit`, which is async-signal-safe, in the forked child before `exec`.
+            unsafe {
+                runner.pre_exec(move || {
+                    if libc::setrlimit(libc::RLIMIT_AS, &limit) == 0 {
+                        Ok(())
+                    } else {
+                        Err(io::Error::last_os_error())
+                    }
+                });
+            }
+        }
 
         Solution {
             id: String::from(id),
@@ -25,6 +103,9 @@ impl Solution {
                 .join(source.to_string())
                 .join(id),
             runner,
+            stdio: source.stdio(),
+            limits,
+            usage: None,
         }
     }
 
@@ -33,6 +114,11 @@ impl Solution {
         &self.id
     }
 
+    /// Returns the resources used by the latest run of the [`Solution`], if it has been run.
+    pub fn usage(&self) -> Option<Usage> {
+        self.usage
+    }
+
     /// Returns the `PathBuf` to the file containing the solution.
     pub fn solfile(&self, ext: &str) -> PathBuf {
         let mut solfile = self.prob_dir.join("sol");
@@ -41,14 +127,339 @@ impl Solution {
         solfile
     }
 
-    /// Runs the compiled solution-testing bin via [`Solution`]'s `runner` command.
-    pub fn run(&mut self) -> Result<OutputStream, OutputStream> {
-        let output = self
-            .runner
-            .output()
+    /// Runs the compiled solution-testing bin via [`Solution`]'s `runner` command, or judges the compiled program
+    /// against the samples of the problem if the problem is a stdin/stdout one.
+    pub fn run(&mut self) -> Result<OutputStream, Failure> {
+        self.usage = Some(Usage::default());
+        if self.stdio {
+            return self.judge();
+        }
+
+        let execution = self.execute(vec![]);
+        let output = &execution.output;
+
+        let output_streams = OutputStream::from(output);
+        if execution.timed_out {
+            Err(Failure::TimedOut(output_streams))
+        } else if self.out_of_memory(output) {
+            Err(Failure::OutOfMemory(output_streams))
+        } else if output.status.success() {
+            Ok(output_streams)
+        } else {
+            Err(Failure::Failed(output_streams))
+        }
+    }
+
+    /// Pipes each sample input under the `samples` directory of the problem into the compiled program via
+    /// [`Solution`]'s `runner` command, and diffs its stdout against the expected sample output.
+    fn judge(&mut self) -> Result<OutputStream, Failure> {
+        let samples_dir = self.prob_dir.join("samples");
+        let mut samples = fs::read_dir(&samples_dir)
+            .map_err(|err| {
+                Failure::Failed(OutputStream::new(
+                    String::new(),
+                    format!("Can't read samples at {}: {err}", samples_dir.display()),
+                ))
+            })?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "in"))
+            .collect::<Vec<_>>();
+        samples.sort_by_key(|path| {
+            let stem = path
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string();
+            (stem.parse::<usize>().unwrap_or(usize::MAX), stem)
+        });
+
+        let (mut report, mut stderr, mut failed) = (String::new(), String::new(), 0);
+        let (mut timed_out, mut out_of_memory) = (false, false);
+        for sample in &samples {
+            let name = format!("sample_{}", sample.file_stem().unwrap_or_default().to_string_lossy());
+            let input = fs::read(sample).expect("Failed to read sample input! Error");
+            let expected = fs::read_to_string(sample.with_extension("out")).unwrap_or_default();
+
+            let execution = self.execute(input);
+            let output = &execution.output;
+
+            let got = String::from_utf8_lossy(&output.stdout);
+            stderr.push_str(&String::from_utf8_lossy(&output.stderr));
+
+            match (output.status.success(), diff(&expected, &got)) {
+                _ if execution.timed_out => {
+                    failed += 1;
+                    timed_out = true;
+                    report.push_str(&format!(
+                        "test {name} ... FAILED\n    time limit exceeded after {}s\n",
+                        self.limits.time.as_secs_f64()
+                    ));
+                }
+                _ if self.out_of_memory(output) => {
+                    failed += 1;
+                    out_of_memory = true;
+                    report.push_str(&format!(
+                        "test {name} ... FAILED\n    memory limit exceeded at {}MB\n",
+                        self.limits.memory.unwrap_or_default()
+                    ));
+                }
+                (true, None) => report.push_str(&format!("test {name} ... ok\n")),
+                (success, difference) => {
+                    failed += 1;
+                    report.push_str(&format!("test {name} ... FAILED\n"));
+                    if !success {
+                        report.push_str(&format!("    exited with {}\n", output.status));
+                    }
+                    if let Some(difference) = difference {
+                        report.push_str(&format!(
+                            "    {difference}\n    expected:\n{}    got:\n{}",
+                            indent(&expected),
+                            indent(&got)
+                        ));
+                    }
+                }
+            }
+        }
+
+        report.push_str(&format!(
+            "\ntest result: {}. {} passed; {failed} failed\n",
+            if failed == 0 { "ok" } else { "FAILED" },
+            samples.len() - failed
+        ));
+
+        let output_streams = OutputStream::new(report, stderr);
+        if timed_out {
+            Err(Failure::TimedOut(output_streams))
+        } else if out_of_memory {
+            Err(Failure::OutOfMemory(output_streams))
+        } else if failed == 0 && !samples.is_empty() {
+            Ok(output_streams)
+        } else {
+            Err(Failure::Failed(output_streams))
+        }
+    }
+
+    /// Runs [`Solution`]'s `runner` command with `input` piped into its stdin, and adds the resources it used to
+    /// those of the latest run of the [`Solution`].
+    fn execute(&mut self, input: Vec<u8>) -> Execution {
+        let execution = execute(&mut self.runner, input, self.limits.time)
             .expect("Failed to run compiled binary for solution-testing! Error");
+        let usage = self.usage.get_or_insert_with(Usage::default);
+        usage.wall_time += execution.usage.wall_time;
+        usage.cpu_time += execution.usage.cpu_time;
+        usage.peak_memory = usage.peak_memory.max(execution.usage.peak_memory);
+
+        execution
+    }
+
+    /// Returns whether the unsuccessful run with `output` failed for running out of memory under the memory limit.
+    fn out_of_memory(&self, output: &Output) -> bool {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        self.limits.memory.is_some()
+            && !output.status.success()
+            && OUT_OF_MEMORY_MARKERS
+                .iter()
+                .any(|marker| stderr.contains(marker))
+    }
+}
+
+/// A finished run of a command.
+struct Execution {
+    output: Output,
+    /// Whether the run was killed for exceeding its time limit.
+    timed_out: bool,
+    usage: Usage,
+}
+
+/// The ID of the process group of the running command, or zero if there's none.
+static RUNNING_GROUP: AtomicI32 = AtomicI32::new(0);
+
+/// Handles `signal` by killing the process group of the running command, which is outside the foreground process group
+/// of the terminal and so isn't sent its signals, and then dying of `signal` as if unhandled.
+extern "C" fn kill_running_group(signal: libc::c_int) {
+    let group = RUNNING_GROUP.load(Ordering::SeqCst);
+
+    // SAFETY: `kill`, `signal` and `raise` are async-signal-safe, and `kill` is only called with a process group ID.
+    unsafe {
+        if group > 0 {
+            libc::kill(-group, libc::SIGKILL);
+        }
+        libc::signal(signal, libc::SIG_DFL);
+        libc::raise(signal);
+    }
+}
+
+/// Runs `command` in its own process group with `input` piped into its stdin, killing the whole group if it runs for
+/// longer than `timeout`, or if proctor is interrupted or terminated meanwhile.
+fn execute(command: &mut Command, input: Vec<u8>, timeout: Duration) -> io::Result<Execution> {
+    static HANDLE_SIGNALS: Once = Once::new();
+    HANDLE_SIGNALS.call_once(|| {
+        for signal in [libc::SIGINT, libc::SIGTERM] {
+            // SAFETY: `kill_running_group` only makes async-signal-safe calls.
+            unsafe { libc::signal(signal, kill_running_group as extern "C" fn(libc::c_int) as libc::sighandler_t) };
+        }
+    });
+
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .process_group(0)
+        .spawn()?;
+
+    let mut stdin = child
+        .stdin
+        .take()
+        .expect("Failed to open stdin of compiled binary! Error");
+    let writer = thread::spawn(move || stdin.write_all(&input));
+    let reader = |mut pipe: Box<dyn Read + Send>| {
+        thread::spawn(move || {
+            let mut buf = vec![];
+            let _ = pipe.read_to_end(&mut buf);
+            buf
+        })
+    };
+    let stdout = reader(Box::new(
+        child
+            .stdout
+            .take()
+            .expect("Failed to open stdout of compiled binary! Error"),
+    ));
+    let stderr = reader(Box::new(
+        child
+            .stderr
+            .take()
+            .expect("Failed to open stderr of compiled binary! Error"),
+    ));
+
+    // The child is reaped as soon as it exits by a waiter thread, timing it precisely, while it's killed from here once
+    // its time is up.
+    let pid = child.id() as libc::pid_t;
+    RUNNING_GROUP.store(pid, Ordering::SeqCst);
+    let start = Instant::now();
+    let (exited, exit) = mpsc::channel();
+    let waiter = thread::spawn(move || {
+        let reaped = reap(pid);
+        RUNNING_GROUP.store(0, Ordering::SeqCst);
+        let _ = exited.send(());
+
+        (reaped, start.elapsed())
+    });
+    let timed_out = exit.recv_timeout(timeout) == Err(RecvTimeoutError::Timeout);
+    if timed_out {
+        // SAFETY: `kill` is called with the ID of the process group led by the child, which is yet to be reaped.
+        unsafe { libc::kill(-pid, libc::SIGKILL) };
+    }
+    let (reaped, wall_time) = waiter
+        .join()
+        .expect("Failed to wait for compiled binary! Error");
+    let (status, rusage) = reaped?;
+    let _ = writer.join();
+
+    let time = |tv: libc::timeval| Duration::new(tv.tv_sec as u64, tv.tv_usec as u32 * 1000);
+    Ok(Execution {
+        output: Output { status, stdout: stdout.join().unwrap_or_default(), stderr: stderr.join().unwrap_or_default() },
+        timed_out,
+        usage: Usage {
+            wall_time,
+            cpu_time: time(rusage.ru_utime) + time(rusage.ru_stime),
+            peak_memory: u64::try_from(rusage.ru_maxrss).unwrap_or_default(),
+        },
+    })
+}
+
+/// Waits for the child process `pid` to exit and reaps it via `wait4`, returning its exit status and resource usage.
+fn reap(pid: libc::pid_t) -> io::Result<(ExitStatus, libc::rusage)> {
+    let mut status = 0;
+    // SAFETY: `rusage` is plain old data, for which all zeroes is a valid value.
+    let mut rusage = unsafe { mem::zeroed::<libc::rusage>() };
+
+    loop {
+        // SAFETY: `wait4` only writes to `status` and `rusage`, which outlive the call.
+        match unsafe { libc::wait4(pid, &mut status, 0, &mut rusage) } {
+            -1 => {
+                let err = io::Error::last_os_error();
+                if err.kind() != io::ErrorKind::Interrupted {
+                    return Err(err);
+                }
+            }
+            _ => return Ok((ExitStatus::from_raw(status), rusage)),
+        }
+    }
+}
+
+/// Returns the description of the first difference between the lines of `expected` and `got`, ignoring trailing
+/// whitespace, if there's any.
+fn diff(expected: &str, got: &str) -> Option<String> {
+    fn lines(s: &str) -> Vec<&str> {
+        let mut lines = s.lines().map(str::trim_end).collect::<Vec<_>>();
+        while lines.last().is_some_and(|l| l.is_empty()) {
+            lines.pop();
+        }
+
+        lines
+    }
+
+    let (expected, got) = (lines(expected), lines(got));
+
+    (0..expected.len().max(got.len()))
+        .find(|&i| expected.get(i) != got.get(i))
+        .map(|i| {
+            format!(
+                "line {}: expected `{}`, got `{}`",
+                i + 1,
+                expected.get(i).unwrap_or(&"<EOF>"),
+                got.get(i).unwrap_or(&"<EOF>")
+            )
+        })
+}
+
+/// Returns `s` with each of its lines indented.
+fn indent(s: &str) -> String {
+    s.lines().map(|l| format!("        {l}\n")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a [`Solution`] in `lang` to a LeetCode problem, held to a memory limit of 256MB.
+    fn limited(lang: &Lang, optimized: bool) -> Solution {
+        let config = Config::new(String::from("."), String::from("."));
+        let limits = Limits { time: Duration::from_secs(1), memory: Some(256) };
+
+        Solution::new("0001", lang, &Source::LeetCode, &config, limits, optimized)
+    }
+
+    /// Returns the `ASAN_OPTIONS` the runs of `solution` are given, if any.
+    fn asan_options(solution: &Solution) -> Option<String> {
+        solution
+            .runner
+            .get_envs()
+            .find(|(key, _)| *key == "ASAN_OPTIONS")
+            .and_then(|(_, value)| value)
+            .map(|value| value.to_string_lossy().to_string())
+    }
+
+    #[test]
+    fn cpp_memory_limit_is_enforced_by_address_sanitizer() {
+        let options = asan_options(&limited(&Lang::Cpp, false)).unwrap();
+
+        assert!(options.contains("hard_rss_limit_mb=256"), "{options}");
+        assert!(options.contains("allocator_may_return_null=1"), "{options}");
+        assert_eq!(asan_options(&limited(&Lang::Cpp, true)), None);
+        assert_eq!(asan_options(&limited(&Lang::Rust, false)), None);
+    }
+
+    #[test]
+    fn address_sanitizer_rss_limit_is_out_of_memory() {
+        let output = Output {
+            status: ExitStatus::from_raw(1 << 8),
+            stdout: vec![],
+            stderr: b"==10070==AddressSanitizer: hard rss limit exhausted (256Mb vs 362Mb)\n".to_vec(),
+        };
 
-        let output_streams = OutputStream::from(&output);
-        if output.status.success() { Ok(output_streams) } else { Err(output_streams) }
+        assert!(limited(&Lang::Cpp, false).out_of_memory(&output));
     }
 }
@@ -0,0 +1,49 @@
+use std::fs;
+use std::io;
+
+use strum::Display;
+
+use crate::modules::config::Config;
+use crate::modules::source::Source;
+
+/// The file under a problem directory recording whether its solution last passed.
+const STATUS_FILE: &str = ".status";
+
+/// The local status of a problem under the solution root.
+#[derive(Clone, Copy, Debug, Display, PartialEq)]
+pub enum Status {
+    #[strum(to_string = "-")]
+    Unattempted,
+    #[strum(to_string = "attempted")]
+    Attempted,
+    #[strum(to_string = "passed")]
+    Passed,
+    #[strum(to_string = "failed")]
+    Failed,
+}
+
+impl Status {
+    /// Returns the status of problem `id` of `source`, by whether it has a solution, and whether its solution last
+    /// passed if it has been run.
+    pub fn of(id: &str, source: &Source, config: &Config) -> Self {
+        let prob_dir = config.prob_dir(source, id);
+        let has_solution = fs::read_dir(&prob_dir).is_ok_and(|mut entries| {
+            entries.any(|entry| entry.is_ok_and(|entry| entry.path().file_stem().is_some_and(|stem| stem == "sol")))
+        });
+
+        match fs::read_to_string(prob_dir.join(STATUS_FILE))
+            .as_deref()
+            .map(str::trim)
+        {
+            Ok("passed") => Status::Passed,
+            Ok("failed") => Status::Failed,
+            _ if has_solution => Status::Attempted,
+            _ => Status::Unattempted,
+        }
+    }
+
+    /// Records the status as the latest one of problem `id` of `source`.
+    pub fn record(self, id: &str, source: &Source, config: &Config) -> io::Result<()> {
+        fs::write(config.prob_dir(source, id).join(STATUS_FILE), self.to_string())
+    }
+}
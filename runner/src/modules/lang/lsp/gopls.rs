@@ -0,0 +1,64 @@
+use std::error::Error;
+use std::path::PathBuf;
+use std::process::Command;
+
+use colored::Colorize;
+use serde::Deserialize;
+
+use crate::modules::config::Config;
+use crate::modules::dev_env::Setup;
+use crate::modules::lang::Lang;
+
+use super::Lsp;
+
+/// A structure for `gopls` config for [`Lsp`] trait application.
+///
+/// The solution root is set up as a single module requiring the Go library of this project, which `gopls` picks up
+/// through a workspace file.
+#[derive(Deserialize)]
+pub struct Gopls {
+    version: String,
+}
+
+impl Gopls {
+    /// Returns a [`Gopls`] using values from `config`.
+    pub fn from(config: &Config) -> Self {
+        if let Some(goconf) = config.lang.get(&Lang::Go.to_string()) {
+            if let Some(goplsconf) = goconf.get("gopls") {
+                serde_json::from_value(goplsconf.clone()).unwrap()
+            } else {
+                Gopls { version: String::from("1.21") }
+            }
+        } else {
+            panic!(
+                "{}: Can't find entry for {} in lang of config!",
+                "ERR".red().bold(),
+                Lang::Go.get_name().cyan().bold()
+            )
+        }
+    }
+}
+
+impl Lsp for Gopls {
+    fn generate_setup(&self, config: &Config) -> Result<(Setup, Option<Command>), Box<dyn Error>> {
+        let library = format!("{}/lib/go", config.project_dir_str);
+
+        Ok((
+            Setup::from(
+                Lang::Go,
+                PathBuf::from(&config.sol_dir_str),
+                vec![
+                    (
+                        PathBuf::from("go.mod"),
+                        format!(
+                            "module solutions\n\ngo {}\n\nrequire proctor v0.0.0\n\nreplace proctor => {library}\n",
+                            self.version
+                        ),
+                    ),
+                    (PathBuf::from("go.work"), format!("go {}\n\nuse (\n\t.\n\t{library}\n)\n", self.version)),
+                ],
+            ),
+            None,
+        ))
+    }
+}
@@ -0,0 +1,140 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::{fs, io};
+
+use colored::Colorize;
+use serde::Deserialize;
+use strum::IntoEnumIterator;
+
+use crate::modules::config::Config;
+use crate::modules::dev_env::Setup;
+use crate::modules::lang::Lang;
+use crate::modules::source::Source;
+
+use super::Lsp;
+
+/// A structure for `jdtls` config for [`Lsp`] trait application.
+///
+/// As every solution defines its own `Solution` class, each solution directory is set up as a separate Eclipse
+/// project, which `jdtls` imports individually.
+#[derive(Default, Deserialize)]
+pub struct Jdtls {
+    #[serde(default)]
+    libraries: Vec<String>,
+    #[serde(skip)]
+    projects: Vec<PathBuf>,
+}
+
+impl Jdtls {
+    /// Returns a [`Jdtls`] using values from `config`.
+    pub fn from(config: &Config) -> Self {
+        if let Some(javaconf) = config.lang.get(&Lang::Java.to_string()) {
+            let mut jdtls: Jdtls = javaconf
+                .get("jdtls")
+                .map_or_else(Jdtls::default, |jdtlsconf| serde_json::from_value(jdtlsconf.clone()).unwrap());
+            jdtls
+                .libraries
+                .insert(0, format!("{}/lib/java/build", config.project_dir_str));
+            jdtls
+                .parse_directory_as_projects(Path::new(&config.sol_dir_str))
+                .unwrap_or_else(|_| panic!("Couldn't parse directory structure of solution root {}", config.sol_dir_str));
+
+            jdtls
+        } else {
+            panic!(
+                "{}: Can't find entry for {} in lang of config!",
+                "ERR".red().bold(),
+                Lang::Java.get_name().cyan().bold()
+            )
+        }
+    }
+
+    /// Adds every directory under `sol_dir` containing a Java solution as a project of [`Jdtls`].
+    fn parse_directory_as_projects(&mut self, sol_dir: &Path) -> io::Result<()> {
+        Source::iter().try_for_each(|source| {
+            let source_dir = PathBuf::from(source.to_string());
+            if !sol_dir.join(&source_dir).is_dir() {
+                return Ok(());
+            }
+
+            for entry in fs::read_dir(sol_dir.join(&source_dir))? {
+                let path = entry?.path();
+
+                if path.is_dir() && path.join("sol.java").exists() {
+                    self.projects
+                        .push(source_dir.join(path.file_name().unwrap_or_default()));
+                }
+            }
+
+            Ok::<(), io::Error>(())
+        })?;
+
+        self.projects.sort_unstable();
+
+        Ok(())
+    }
+
+    /// Returns the contents of the Eclipse `.project` file for the project `name`.
+    fn project_file(name: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<projectDescription>
+    <name>{name}</name>
+    <buildSpec>
+        <buildCommand>
+            <name>org.eclipse.jdt.core.javabuilder</name>
+        </buildCommand>
+    </buildSpec>
+    <natures>
+        <nature>org.eclipse.jdt.core.javanature</nature>
+    </natures>
+</projectDescription>
+"#
+        )
+    }
+
+    /// Returns the contents of the Eclipse `.classpath` file of a project.
+    fn classpath_file(&self) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<classpath>
+    <classpathentry kind="src" path=""/>
+    <classpathentry kind="con" path="org.eclipse.jdt.launching.JRE_CONTAINER"/>
+{}    <classpathentry kind="output" path="bin"/>
+</classpath>
+"#,
+            self.libraries
+                .iter()
+                .map(|library| format!("    <classpathentry kind=\"lib\" path=\"{library}\"/>\n"))
+                .collect::<String>()
+        )
+    }
+}
+
+impl Lsp for Jdtls {
+    fn generate_setup(&self, config: &Config) -> Result<(Setup, Option<Command>), Box<dyn Error>> {
+        Ok((
+            Setup::from(
+                Lang::Java,
+                PathBuf::from(&config.sol_dir_str),
+                self.projects
+                    .iter()
+                    .flat_map(|project| {
+                        let name = project
+                            .iter()
+                            .map(|component| component.to_string_lossy())
+                            .collect::<Vec<_>>()
+                            .join("-");
+
+                        [
+                            (project.join(".project"), Jdtls::project_file(&name)),
+                            (project.join(".classpath"), self.classpath_file()),
+                        ]
+                    })
+                    .collect(),
+            ),
+            None,
+        ))
+    }
+}
@@ -1,6 +1,9 @@
 mod clangd;
+mod gopls;
+mod jdtls;
 mod pyright;
 mod rust_analyzer;
+mod tsserver;
 
 use std::error::Error;
 use std::process::Command;
@@ -9,8 +12,11 @@ use crate::modules::config::Config;
 use crate::modules::dev_env::Setup;
 
 pub use self::clangd::Clangd;
+pub use self::gopls::Gopls;
+pub use self::jdtls::Jdtls;
 pub use self::pyright::Pyright;
 pub use self::rust_analyzer::RustAnalyzer;
+pub use self::tsserver::Tsserver;
 
 /// A trait that allows LSP config generation.
 pub trait Lsp {
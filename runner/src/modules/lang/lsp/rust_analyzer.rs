@@ -78,7 +78,7 @@ impl RustAnalyzer {
 
     /// Returns a [`RustAnalyzer`] using values from `config`.
     pub fn from(config: &Config) -> RustAnalyzer {
-        if config.lang.get(&Lang::Rust.to_string()).is_some() {
+        if config.lang.contains_key(&Lang::Rust.to_string()) {
             let mut rust_analyzer = RustAnalyzer::new(Path::new(&config.project_dir_str));
             rust_analyzer
                 .parse_directory_as_crates(Path::new(&config.sol_dir_str))
@@ -111,7 +111,12 @@ impl RustAnalyzer {
     /// Parses and adds qualifying directory under `sol_dir` as a [`Crate`] into [`RustAnalyzer`].
     fn parse_directory_as_crates(&mut self, sol_dir: &Path) -> io::Result<()> {
         Source::iter().try_for_each(|source| {
-            for entry in fs::read_dir(sol_dir.join(source.to_string()))? {
+            let source_dir = sol_dir.join(source.to_string());
+            if !source_dir.is_dir() {
+                return Ok(());
+            }
+
+            for entry in fs::read_dir(source_dir)? {
                 self.cratify(entry?.path().as_path());
             }
 
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::PathBuf;
+use std::process::Command;
+
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+
+use crate::modules::config::Config;
+use crate::modules::dev_env::Setup;
+use crate::modules::lang::Lang;
+
+use super::Lsp;
+
+/// `tsconfig.json` serializer for `typescript-language-server`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tsserver {
+    compiler_options: CompilerOptions,
+    include: Vec<String>,
+}
+
+/// `compilerOptions` component of a `tsconfig.json`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CompilerOptions {
+    target: String,
+    module: String,
+    strict: bool,
+    no_emit: bool,
+    base_url: String,
+    paths: HashMap<String, Vec<String>>,
+    type_roots: Vec<String>,
+}
+
+/// User-configurable values of [`Tsserver`].
+#[derive(Deserialize)]
+struct TsserverConfig {
+    #[serde(default)]
+    strict: bool,
+}
+
+impl Tsserver {
+    /// Returns a [`Tsserver`] detailing a `tsconfig.json` for the dev environment.
+    pub fn from(config: &Config) -> Self {
+        if let Some(tsconf) = config.lang.get(&Lang::TypeScript.to_string()) {
+            let TsserverConfig { strict } = tsconf
+                .get("tsserver")
+                .map_or(TsserverConfig { strict: false }, |tsserverconf| {
+                    serde_json::from_value(tsserverconf.clone()).unwrap()
+                });
+            let library = format!("{}/lib/ts", config.project_dir_str);
+
+            Tsserver {
+                compiler_options: CompilerOptions {
+                    target: String::from("es2022"),
+                    module: String::from("commonjs"),
+                    strict,
+                    no_emit: true,
+                    base_url: String::from("."),
+                    paths: HashMap::from([(String::from("proctor/*"), vec![format!("{library}/build/proctor/*")])]),
+                    type_roots: vec![format!("{library}/node_modules/@types")],
+                },
+                include: vec![String::from("**/sol.ts")],
+            }
+        } else {
+            panic!(
+                "{}: Can't find entry for {} in lang of config!",
+                "ERR".red().bold(),
+                Lang::TypeScript.get_name().cyan().bold()
+            )
+        }
+    }
+}
+
+impl Lsp for Tsserver {
+    fn generate_setup(&self, config: &Config) -> Result<(Setup, Option<Command>), Box<dyn Error>> {
+        Ok((
+            Setup::from(
+                Lang::TypeScript,
+                PathBuf::from(&config.sol_dir_str),
+                vec![(PathBuf::from("tsconfig.json"), serde_json::to_string_pretty(self)?)],
+            ),
+            None,
+        ))
+    }
+}
@@ -1,10 +1,12 @@
 mod lsp;
 
+use std::env;
 use std::error::Error;
 use std::process::Command;
 
 use regex::{Error as RegexError, Regex};
 use serde::Deserialize;
+use serde_json::Value;
 use strum::{Display, EnumCount, EnumIter, EnumProperty, EnumString};
 
 use super::config::Config;
@@ -15,8 +17,9 @@ use self::lsp::Lsp;
 
 const CLANG_COLOR_ARGS: &[&str] = &["--force-colors", "true"];
 const CLANG_COMPILE_FLAGS: &[&str] = &["-std=c++20", "-stdlib=libc++", "-Wall", "-fsanitize=address", "-g3", "-O2"];
+const CLANG_OPTIMIZED_COMPILE_FLAGS: &[&str] = &["-std=c++20", "-stdlib=libc++", "-Wall", "-O2"];
 const RUSTC_COLOR_ARGS: &[&str] = &["--color", "always"];
-const RUSTC_COMPILE_FLAGS: &[&str] = &["--color", "always", "--edition", "2021", "--test"];
+const RUSTC_COMPILE_FLAGS: &[&str] = &["--color", "always", "--edition", "2021"];
 
 /// An enum listing available code languages.
 #[derive(Clone, Debug, Deserialize, Display, EnumCount, EnumIter, EnumProperty, EnumString, PartialEq)]
@@ -24,12 +27,21 @@ pub enum Lang {
     #[strum(serialize = "cpp", props(name = "C++"))]
     #[serde(rename = "cpp")]
     Cpp,
+    #[strum(serialize = "go", props(name = "Go"))]
+    #[serde(rename = "go")]
+    Go,
+    #[strum(serialize = "java", props(name = "Java"))]
+    #[serde(rename = "java")]
+    Java,
     #[strum(serialize = "py", props(name = "Python3"))]
     #[serde(rename = "py")]
     Python,
     #[strum(serialize = "rs", props(name = "Rust"))]
     #[serde(rename = "rs")]
     Rust,
+    #[strum(serialize = "ts", props(name = "TypeScript"))]
+    #[serde(rename = "ts")]
+    TypeScript,
 }
 
 impl Lang {
@@ -41,7 +53,7 @@ impl Lang {
     /// Get all comment symbols of the language.
     pub fn comments(&self) -> Vec<&'static str> {
         match self {
-            Lang::Cpp => vec!["//", "/**", " *"],
+            Lang::Cpp | Lang::Go | Lang::Java | Lang::TypeScript => vec!["//", "/**", " *"],
             Lang::Python => vec!["#"],
             Lang::Rust => vec!["//"],
         }
@@ -55,18 +67,33 @@ impl Lang {
             Lang::Cpp => {
                 let mut runner = Command::new(binfile);
                 runner
-                    .arg("--success")
+                    .args(["--success", "--duration=true"])
                     .args(CLANG_COLOR_ARGS)
                     .env("LD_LIBRARY_PATH", format!("{}/lib/cpp/build", config.project_dir_str));
 
                 runner
             }
+            Lang::Go => {
+                let mut runner = Command::new(binfile);
+                runner.arg("-test.v");
+
+                runner
+            }
+            Lang::Java => {
+                let mut runner = Command::new("java");
+                runner
+                    .arg("-cp")
+                    .arg(format!("{}:{}/lib/java/build", binfile.display(), config.project_dir_str))
+                    .arg("SolutionTest");
+
+                runner
+            }
             Lang::Python => {
                 let mut runner = Command::new("python");
                 runner
                     .arg(binfile)
                     .arg("-v")
-                    .env("PATH", format!("{}/venv/py311/bin:$PATH", config.sol_dir_str));
+                    .env("PATH", format!("{}/venv/py311/bin:{}", config.sol_dir_str, env::var("PATH").unwrap_or_default()));
 
                 runner
             }
@@ -74,13 +101,63 @@ impl Lang {
                 let mut runner = Command::new(binfile);
                 runner.arg("--show-output").args(RUSTC_COLOR_ARGS);
 
+                runner
+            }
+            Lang::TypeScript => {
+                let mut runner = Command::new("node");
+                runner
+                    .args(["--test", "--test-reporter=spec"])
+                    .arg(binfile.join("sol.js"))
+                    .env("NODE_PATH", format!("{}/lib/ts/build", config.project_dir_str));
+
                 runner
             }
         }
     }
 
-    /// Returns the [`Command`] that executes the language compiler.
-    pub fn compiler(&self, config: &Config) -> Command {
+    /// Returns the [`Command`] that executes the stdin/stdout program `binfile`.
+    pub fn executor(&self, config: &Config) -> Command {
+        let binfile = config.binfile(&self.to_string());
+
+        match self {
+            Lang::Cpp => {
+                let mut executor = Command::new(binfile);
+                executor.env("LD_LIBRARY_PATH", format!("{}/lib/cpp/build", config.project_dir_str));
+
+                executor
+            }
+            Lang::Go | Lang::Rust => Command::new(binfile),
+            Lang::Java => {
+                let mut executor = Command::new("java");
+                executor
+                    .arg("-cp")
+                    .arg(format!("{}:{}/lib/java/build", binfile.display(), config.project_dir_str))
+                    .arg("Main");
+
+                executor
+            }
+            Lang::Python => {
+                let mut executor = Command::new("python");
+                executor
+                    .arg(binfile)
+                    .env("PATH", format!("{}/venv/py311/bin:{}", config.sol_dir_str, env::var("PATH").unwrap_or_default()));
+
+                executor
+            }
+            Lang::TypeScript => {
+                let mut executor = Command::new("node");
+                executor
+                    .arg(binfile.join("sol.js"))
+                    .env("NODE_PATH", format!("{}/lib/ts/build", config.project_dir_str));
+
+                executor
+            }
+        }
+    }
+
+    /// Returns the [`Command`] that executes the language compiler, building a stdin/stdout program instead of a
+    /// solution-testing bin if `stdio`.
+    pub fn compiler(&self, config: &Config, stdio: bool, optimized: bool) -> Command {
         match self {
             Lang::Cpp => {
                 let mut compiler = Command::new("clang++");
@@ -90,7 +167,26 @@ impl Lang {
                         format!("-L{}/lib/cpp/build", config.project_dir_str).as_str(),
                         "-lproctor",
                     ])
-                    .args(CLANG_COMPILE_FLAGS);
+                    .args(if optimized { CLANG_OPTIMIZED_COMPILE_FLAGS } else { CLANG_COMPILE_FLAGS });
+
+                compiler
+            }
+            Lang::Go => {
+                let mut compiler = Command::new("bash");
+                compiler
+                    .arg(format!("{}/runner/wrappers/compile_go.sh", config.project_dir_str))
+                    .args(["--lib", format!("{}/lib/go", config.project_dir_str).as_str()]);
+                if stdio {
+                    compiler.arg("--stdio");
+                }
+
+                compiler
+            }
+            Lang::Java => {
+                let mut compiler = Command::new("bash");
+                compiler
+                    .arg(format!("{}/runner/wrappers/compile_java.sh", config.project_dir_str))
+                    .args(["-cp", format!("{}/lib/java/build", config.project_dir_str).as_str()]);
 
                 compiler
             }
@@ -108,6 +204,20 @@ impl Lang {
                         format!("libproctor={}/target/release/libproctor.rlib", config.project_dir_str).as_str(),
                     ])
                     .args(RUSTC_COMPILE_FLAGS);
+                if optimized {
+                    compiler.arg("-O");
+                }
+                if !stdio {
+                    compiler.arg("--test");
+                }
+
+                compiler
+            }
+            Lang::TypeScript => {
+                let mut compiler = Command::new("bash");
+                compiler
+                    .arg(format!("{}/runner/wrappers/compile_ts.sh", config.project_dir_str))
+                    .args(["--lib", format!("{}/lib/ts", config.project_dir_str).as_str()]);
 
                 compiler
             }
@@ -118,45 +228,117 @@ impl Lang {
     pub fn generate_setup(&self, config: &Config) -> Result<(Setup, Option<Command>), Box<dyn Error>> {
         match self {
             Lang::Cpp => lsp::Clangd::from(config).generate_setup(config),
+            Lang::Go => lsp::Gopls::from(config).generate_setup(config),
+            Lang::Java => lsp::Jdtls::from(config).generate_setup(config),
             Lang::Python => lsp::Pyright::from(config).generate_setup(config),
             Lang::Rust => lsp::RustAnalyzer::from(config).generate_setup(config),
+            Lang::TypeScript => lsp::Tsserver::from(config).generate_setup(config),
         }
     }
 
     /// Parses `typ` into the language-appropriate data type name.
     pub fn parse(&self, typ: &str) -> Result<Typ, Box<dyn Error>> {
-        if let Some(caps) = Regex::new(r"(?<type>\w+)(?<arr>\[\])?")?.captures(typ) {
-            let (mut transformed, mut form) = caps
-                .name("type")
-                .map_or((String::new(), Form::Unit), |m| match m.as_str() {
+        if let Some(caps) = Regex::new(r"^(?:list<(?<list>.+)>|(?<arr>.+)\[\]|(?<type>\w+))$")?.captures(typ.trim()) {
+            let (transformed, form) = if let Some(m) = caps.name("list").or_else(|| caps.name("arr")) {
+                let inner = self.parse(m.as_str())?;
+
+                (
+                    match self {
+                        Lang::Cpp => format!(
+                            "vector<{}{}> ",
+                            inner.transformed.trim_end(),
+                            if inner.form == Form::Pointer { "*" } else { "" }
+                        ),
+                        Lang::Go => format!("[]{}", inner.transformed),
+                        Lang::Java if caps.name("list").is_some() => format!("List<{}>", boxed(&inner.transformed)),
+                        Lang::Java => format!("{}[]", inner.transformed),
+                        Lang::Python => format!("List[{}]", inner.transformed),
+                        Lang::Rust => format!("Vec<{}>", inner.transformed),
+                        Lang::TypeScript if inner.form == Form::Pointer => format!("Array<{}>", inner.transformed),
+                        Lang::TypeScript => format!("{}[]", inner.transformed),
+                    },
+                    Form::Array(Box::new(inner.form)),
+                )
+            } else {
+                match caps.name("type").map_or("", |m| m.as_str()) {
                     "integer" => (
                         String::from(match self {
-                            Lang::Cpp | Lang::Python => "int",
+                            Lang::Cpp | Lang::Go | Lang::Java | Lang::Python => "int",
                             Lang::Rust => "i32",
+                            Lang::TypeScript => "number",
+                        }),
+                        Form::Unit,
+                    ),
+                    "long" => (
+                        String::from(match self {
+                            Lang::Cpp => "long long",
+                            Lang::Go => "int64",
+                            Lang::Java => "long",
+                            Lang::Python => "int",
+                            Lang::Rust => "i64",
+                            Lang::TypeScript => "number",
                         }),
                         Form::Unit,
                     ),
                     "double" => (
                         String::from(match self {
-                            Lang::Cpp => "double",
+                            Lang::Cpp | Lang::Java => "double",
+                            Lang::Go => "float64",
                             Lang::Python => "float",
                             Lang::Rust => "f64",
+                            Lang::TypeScript => "number",
                         }),
                         Form::Unit,
                     ),
-                    _ => todo!(),
-                });
-
-            if caps.name("arr").is_some() {
-                (transformed, form) = (
-                    match self {
-                        Lang::Cpp => format!("vector<{}> ", transformed.trim_end()),
-                        Lang::Python => format!("List[{transformed}]"),
-                        Lang::Rust => format!("Vec<{transformed}>"),
-                    },
-                    Form::Array,
-                );
-            }
+                    "boolean" => (
+                        String::from(match self {
+                            Lang::Cpp | Lang::Go | Lang::Python | Lang::Rust => "bool",
+                            Lang::Java | Lang::TypeScript => "boolean",
+                        }),
+                        Form::Unit,
+                    ),
+                    "void" => (
+                        String::from(match self {
+                            Lang::Cpp | Lang::Java | Lang::TypeScript => "void",
+                            Lang::Go => "",
+                            Lang::Python => "None",
+                            Lang::Rust => "()",
+                        }),
+                        Form::Unit,
+                    ),
+                    "string" => (
+                        String::from(match self {
+                            Lang::Cpp | Lang::Go | Lang::TypeScript => "string",
+                            Lang::Java | Lang::Rust => "String",
+                            Lang::Python => "str",
+                        }),
+                        Form::Unit,
+                    ),
+                    "character" => (
+                        String::from(match self {
+                            Lang::Cpp | Lang::Java | Lang::Rust => "char",
+                            Lang::Go => "byte",
+                            Lang::Python => "str",
+                            Lang::TypeScript => "string",
+                        }),
+                        Form::Unit,
+                    ),
+                    datastruct @ ("ListNode" | "TreeNode") => (
+                        match self {
+                            Lang::Cpp | Lang::Java => String::from(datastruct),
+                            Lang::Go => format!("*{datastruct}"),
+                            Lang::Python => format!("Optional[{datastruct}]"),
+                            Lang::Rust => match datastruct {
+                                "TreeNode" => String::from("Option<Rc<RefCell<TreeNode>>>"),
+                                _ => format!("Option<Box<{datastruct}>>"),
+                            },
+                            Lang::TypeScript => format!("{datastruct} | null"),
+                        },
+                        Form::Pointer,
+                    ),
+                    _ => return Err(format!("Unsupported data type {typ}").into()),
+                }
+            };
 
             Ok(Typ { initial: String::from(typ), transformed, form })
         } else {
@@ -166,40 +348,157 @@ impl Lang {
 
     /// Processes `examples` into the language-appropriate form.
     pub fn process(&self, typ: &Typ, example: &str) -> String {
-        match self {
-            Lang::Cpp => {
-                let cleaned = example.trim_matches(|c| c == '[' || c == ']');
-                let mut it = typ.initial.chars();
+        let literal = serde_json::from_str::<Value>(example)
+            .map_or_else(|_| String::from(example), |value| self.render(typ.base(), &typ.initial, &typ.form, &value));
+
+        match (self, &typ.form) {
+            (Lang::Cpp, Form::Pointer) => format!(" = {literal}"),
+            (Lang::Cpp, _) => format!("({literal})"),
+            (Lang::Go, Form::Array(_)) => format!("{}{literal}", typ.transformed),
+            (Lang::Java, Form::Array(_)) if !typ.initial.starts_with("list<") => format!("new {}{literal}", typ.transformed),
+            _ => literal,
+        }
+    }
+
+    /// Renders the JSON `value` of data type `initial`, with form `form` and base data type `base`, into the
+    /// language-appropriate form.
+    fn render(&self, base: &str, initial: &str, form: &Form, value: &Value) -> String {
+        match (form, value) {
+            (Form::Array(inner), Value::Array(values)) => {
+                let inner_initial = initial
+                    .strip_prefix("list<")
+                    .and_then(|s| s.strip_suffix('>'))
+                    .or_else(|| initial.strip_suffix("[]"))
+                    .unwrap_or(initial);
+                let elements = values
+                    .iter()
+                    .map(|v| self.render(base, inner_initial, inner, v))
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                match self {
+                    Lang::Cpp => format!("{{ {elements} }}"),
+                    Lang::Go => format!("{{{elements}}}"),
+                    Lang::Java if initial.starts_with("list<") => format!("List.of({elements})"),
+                    Lang::Java => format!("{{{elements}}}"),
+                    Lang::Python => format!("[{elements}]"),
+                    Lang::Rust => format!("vec![{elements}]"),
+                    Lang::TypeScript => format!("[{elements}]"),
+                }
+            }
+            (Form::Pointer, Value::Array(values)) => {
+                let elements = values
+                    .iter()
+                    .map(|v| match (self, base, v) {
+                        (Lang::Rust, "TreeNode", Value::Null) => String::from("None"),
+                        (Lang::Rust, "TreeNode", v) => format!("Some({v})"),
+                        _ => self.literal(base, v),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",");
+                let mut it = base.chars();
                 let datastruct = match it.next() {
                     None => String::new(),
                     Some(c) => c.to_lowercase().collect::<String>() + it.as_str(),
                 };
 
-                match typ.form {
-                    Form::Unit => format!("({cleaned})"),
-                    Form::Array => format!("({{ {cleaned} }})"),
-                    Form::Pointer => format!(" = {datastruct}From(vector<int>({{ {cleaned} }}))"),
+                match self {
+                    Lang::Cpp => format!("{datastruct}From({{ {elements} }})"),
+                    Lang::Go => format!("{datastruct}From([]{}{{{elements}}})", if base == "TreeNode" { "any" } else { "int" }),
+                    Lang::Java => {
+                        format!("{base}.from(new {}[]{{{elements}}})", if base == "TreeNode" { "Integer" } else { "int" })
+                    }
+                    Lang::Python | Lang::TypeScript => format!("{datastruct}From([{elements}])"),
+                    Lang::Rust => format!("{base}::from(vec![{elements}])"),
                 }
             }
-            Lang::Python => {
-                if typ.form == Form::Pointer {
-                    format!("{}From({example})", typ.initial)
-                } else {
-                    String::from(example)
-                }
+            _ => self.literal(base, value),
+        }
+    }
+
+    /// Returns the language-appropriate literal for the JSON `value` of data type `typ`.
+    fn literal(&self, typ: &str, value: &Value) -> String {
+        match (self, value) {
+            (Lang::Cpp | Lang::Go | Lang::Java | Lang::Rust, Value::String(s)) if typ == "character" => {
+                format!("'{}'", s.escape_default())
             }
-            Lang::Rust => match typ.form {
-                Form::Unit => String::from(example),
-                Form::Array => format!("vec!{example}"),
-                Form::Pointer => format!(" {}::from(vec!{example})", typ.initial),
-            },
+            (Lang::Rust, Value::String(_)) => format!("String::from({value})"),
+            (Lang::Java, Value::Number(n)) if typ == "long" => format!("{n}L"),
+            (Lang::Java | Lang::Rust, Value::Number(n)) if typ == "double" && !n.is_f64() => format!("{n}.0"),
+            (Lang::Python, Value::Bool(b)) => String::from(if *b { "True" } else { "False" }),
+            (Lang::Cpp, Value::Null) => String::from("nullopt"),
+            (Lang::Go, Value::Null) => String::from("nil"),
+            (Lang::Java, Value::Null) => String::from("null"),
+            (Lang::Python | Lang::Rust, Value::Null) => String::from("None"),
+            _ => value.to_string(),
         }
     }
 }
 
+/// Returns the boxed Java class name of the Java data type `typ`.
+fn boxed(typ: &str) -> &str {
+    match typ {
+        "int" => "Integer",
+        "long" => "Long",
+        "double" => "Double",
+        "boolean" => "Boolean",
+        "char" => "Character",
+        _ => typ,
+    }
+}
+
 impl LangIter {
-    /// Generates [`Setups`] detailing the setups for all available languages.
+    /// Generates [`Setups`] detailing the setups for the languages having an entry in `lang` of `config`.
     pub fn generate_setups(self, config: &Config) -> Result<Setups, Box<dyn Error>> {
-        self.map(|lang| lang.generate_setup(config)).collect()
+        self.filter(|lang| config.lang.contains_key(&lang.to_string()))
+            .map(|lang| lang.generate_setup(config))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    /// Returns the languages for which setups are generated from a config with entries for `langs` only.
+    fn set_up_langs(langs: &[Lang]) -> Vec<Lang> {
+        let project_dir = format!("{}/..", env!("CARGO_MANIFEST_DIR"));
+        let sol_dir = env::temp_dir().join("proctor-setup");
+        let mut config = Config::new(project_dir, sol_dir.display().to_string());
+        for lang in langs {
+            let entry = if *lang == Lang::Python { json!({ "version": "3.11" }) } else { json!({}) };
+            config.lang.insert(lang.to_string(), entry);
+        }
+
+        Lang::iter()
+            .generate_setups(&config)
+            .unwrap()
+            .into_iter()
+            .map(|(setup, _)| setup.lang)
+            .collect()
+    }
+
+    #[test]
+    fn setup_skips_java_missing_from_config() {
+        let langs = [Lang::Cpp, Lang::Python, Lang::Rust];
+
+        assert_eq!(set_up_langs(&langs), langs);
+    }
+
+    #[test]
+    fn setup_skips_go_missing_from_config() {
+        let langs = [Lang::Cpp, Lang::Java, Lang::Python, Lang::Rust, Lang::TypeScript];
+
+        assert_eq!(set_up_langs(&langs), langs);
+    }
+
+    #[test]
+    fn setup_skips_typescript_missing_from_config() {
+        let langs = [Lang::Cpp, Lang::Go, Lang::Java, Lang::Python, Lang::Rust];
+
+        assert_eq!(set_up_langs(&langs), langs);
     }
 }
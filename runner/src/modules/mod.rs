@@ -1,3 +1,4 @@
+mod catalog;
 pub mod cli;
 mod colorize;
 mod config;
@@ -6,3 +7,4 @@ mod fetcher;
 mod grader;
 mod lang;
 mod source;
+mod submitter;
@@ -0,0 +1,76 @@
+use std::error::Error;
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use colored::Colorize;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::modules::colorize::MoreColorize;
+use crate::modules::config::Config;
+
+use super::Source;
+
+/// An on-disk cache of raw question data, stored per source and problem ID.
+pub struct Cache {
+    dir: PathBuf,
+    refresh: bool,
+    offline: bool,
+}
+
+impl Cache {
+    /// Returns a [`Cache`] rooted at the `proctor` directory of the user cache directory, else at `.cache` under the
+    /// solution root, which bypasses cached data if `refresh` and never queries the network if `offline`.
+    pub fn new(config: &Config, refresh: bool, offline: bool) -> Self {
+        Cache {
+            dir: dirs::cache_dir().map_or_else(|| PathBuf::from(&config.sol_dir_str).join(".cache"), |dir| dir.join("proctor")),
+            refresh,
+            offline,
+        }
+    }
+
+    /// Returns the cached question data for problem `id` of `source`, else the question data queried via `query`,
+    /// which is then cached.
+    pub fn get_or_query<T: DeserializeOwned + Serialize>(
+        &self, source: &Source, id: &str, query: impl FnOnce() -> Result<T, Box<dyn Error>>,
+    ) -> Result<T, Box<dyn Error>> {
+        self.get_or_requery(source, id, |_| false, query)
+    }
+
+    /// Returns the same as [`Cache::get_or_query`], except that cached question data which is `stale` is queried again
+    /// unless offline, e.g. a cached problem list predating a newly released problem.
+    pub fn get_or_requery<T: DeserializeOwned + Serialize>(
+        &self, source: &Source, id: &str, stale: impl FnOnce(&T) -> bool, query: impl FnOnce() -> Result<T, Box<dyn Error>>,
+    ) -> Result<T, Box<dyn Error>> {
+        let cache_file = self.dir.join(source.to_string()).join(format!("{id}.json"));
+
+        if !self.refresh && cache_file.exists() {
+            print!("Reading cached question data from {}... ", cache_file.display().to_string().orange().bold());
+            io::stdout().flush()?;
+
+            let data = serde_json::from_str(&fs::read_to_string(&cache_file)?)?;
+            println!("{}!", "OK".green().bold());
+
+            if self.offline || !stale(&data) {
+                return Ok(data);
+            }
+            println!("Cached question data is {}, querying it again", "stale".yellow().bold());
+        }
+
+        if self.offline {
+            return Err(format!("Question data for problem {id} of {source} isn't cached, and offline mode is on").into());
+        }
+
+        let data = query()?;
+
+        print!("Caching question data into {}... ", cache_file.display().to_string().orange().bold());
+        io::stdout().flush()?;
+
+        fs::create_dir_all(self.dir.join(source.to_string()))?;
+        fs::write(&cache_file, serde_json::to_string(&data)?)?;
+        println!("{}!", "OK".green().bold());
+
+        Ok(data)
+    }
+}
@@ -0,0 +1,117 @@
+use std::error::Error;
+use std::io::{self, Write};
+
+use colored::Colorize;
+use regex::{Captures, Regex};
+use reqwest::blocking::Client;
+use strum::Display;
+
+use crate::modules::lang::Lang;
+
+use super::{unescape, Cache, MetaData, QuestionDetails, Source};
+
+#[derive(Display)]
+enum CodeforcesURL {
+    #[strum(to_string = "https://codeforces.com/contest/{contest}/problem/{index}")]
+    Problem { contest: String, index: String },
+}
+
+/// Splits the problem `id` into its contest ID and problem index, e.g. `1850A1` into `1850` and `A1`.
+fn split(id: &str) -> Result<(&str, &str), Box<dyn Error>> {
+    let pos = id
+        .find(|c: char| !c.is_ascii_digit())
+        .filter(|&pos| pos > 0)
+        .ok_or_else(|| format!("Invalid Codeforces problem ID {id}, expected contest ID followed by problem index"))?;
+
+    Ok(id.split_at(pos))
+}
+
+/// Returns the outer HTML of the first `div` element of class `class` in `html`.
+fn div<'a>(html: &'a str, class: &str) -> Option<&'a str> {
+    let start = html.find(&format!("<div class=\"{class}\">"))?;
+    let mut depth = 0;
+
+    for (pos, _) in html[start..].match_indices("div") {
+        match &html[start + pos - 1..start + pos] {
+            "<" => depth += 1,
+            "/" => {
+                depth -= 1;
+                if depth == 0 {
+                    return html[start + pos..]
+                        .find('>')
+                        .map(|end| &html[start..start + pos + end + 1]);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    None
+}
+
+/// Returns the text of the sample `pre` block `html`, whose lines are either separated by `<br />` or each wrapped in
+/// a `div`.
+fn sample(html: &str) -> String {
+    let (mut text, mut in_tag) = (String::new(), false);
+    for c in html
+        .replace("<br />", "\n")
+        .replace("<br/>", "\n")
+        .replace("</div>", "\n")
+        .chars()
+    {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+
+    unescape(&text)
+        .lines()
+        .map(str::trim_end)
+        .filter(|l| !l.is_empty())
+        .fold(String::new(), |acc, l| acc + l + "\n")
+}
+
+/// Returns the result of querying data associated to problem `id`, with the sample inputs as a JSON array in place of
+/// example testcases.
+pub fn query(id: &str, _lang: &Lang, cache: &Cache) -> Result<QuestionDetails, Box<dyn Error>> {
+    let (contest, index) = split(id)?;
+
+    let statement = &cache.get_or_query(&Source::Codeforces, id, || {
+        print!("Querying problem statement for problem {}... ", id.cyan().bold());
+        io::stdout().flush()?;
+
+        let html = Client::new()
+            .get(CodeforcesURL::Problem { contest: contest.to_string(), index: index.to_string() }.to_string())
+            .header("User-Agent", "rust")
+            .send()?
+            .error_for_status()?
+            .text()?;
+        let statement = div(&html, "problem-statement").ok_or("Can't find problem statement in problem page")?;
+        println!("{}!", "OK".green().bold());
+
+        Ok(statement.to_string())
+    })?;
+
+    let (mut inputs, mut outputs) = (vec![], vec![]);
+    for caps in
+        Regex::new(r#"(?s)<div class="(?<kind>input|output)">.*?<pre[^>]*>(?<sample>.*?)</pre>"#)?.captures_iter(statement)
+    {
+        let samples = if &caps["kind"] == "input" { &mut inputs } else { &mut outputs };
+        samples.push(sample(&caps["sample"]));
+    }
+
+    let desc = Regex::new(r"(?s)<pre[^>]*>(?<sample>.*?)</pre>")?.replace_all(statement, |caps: &Captures| {
+        format!(
+            "<pre>{}</pre>",
+            sample(&caps["sample"])
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+        )
+    });
+
+    Ok((desc.to_string(), None, MetaData::Stdio, serde_json::to_string(&inputs)?, outputs))
+}
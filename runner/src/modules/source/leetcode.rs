@@ -1,65 +1,273 @@
+use std::env;
 use std::error::Error;
+use std::fmt;
 use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
 
 use colored::Colorize;
+use regex::Regex;
 use reqwest::blocking::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use strum::Display;
 
+use crate::modules::config::Config;
 use crate::modules::fetcher::{GraphQLResponse, Method, Request, Response};
 use crate::modules::lang::Lang;
 
-use super::QuestionDetails;
+use super::{unescape, Cache, Difficulty, Filters, Listing, Page, ProblemId, QuestionDetails, Source, Verdict};
+
+/// The base URL of LeetCode, which the environment variable `LEETCODE_BASE_URL` overrides, e.g. with a mock server.
+const BASE_URL: &str = "https://leetcode.com";
+
+/// The interval between polls for the verdict on a submission.
+#[cfg(not(test))]
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+#[cfg(test)]
+const POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// The maximum number of polls for the verdict on a submission.
+const MAX_POLLS: usize = 60;
+
+const QUESTION_QUERY: &str = r#"
+query question($titleSlug: String!) {
+  obj: question(titleSlug: $titleSlug) {
+    questionFrontendId
+    isPaidOnly
+    content
+    metaData
+    codeSnippets {
+      lang
+      langSlug
+      code
+    }
+    exampleTestcases
+  }
+}
+"#;
+
+const DAILY_QUERY: &str = r#"
+query questionOfToday {
+  obj: activeDailyCodingChallengeQuestion {
+    question {
+      questionFrontendId
+    }
+  }
+}
+"#;
 
 const QUESTION_LIST_QUERY: &str = r#"
-query questionList($skip: Int) {
-  obj: questionList(
-    categorySlug: ""
-    limit: 1
-    skip: $skip
-    filters: {}
-  ) {
+query questionList($limit: Int, $skip: Int, $filters: QuestionListFilterInput) {
+  obj: questionList(categorySlug: "", limit: $limit, skip: $skip, filters: $filters) {
+    total: totalNum
     questions: data {
       questionFrontendId
-      content
-      metaData
-      codeSnippets {
-        lang
-        langSlug
-        code
-      }
-      exampleTestcases
+      title
+      difficulty
+      acRate
+      isPaidOnly
     }
   }
 }
 "#;
 
+/// Paths of LeetCode URLs, relative to the base URL.
 #[derive(Display)]
 enum LeetcodeURL {
-    #[strum(to_string = "https://leetcode.com/graphql")]
+    #[strum(to_string = "/problems/{slug}/")]
+    Problem { slug: String },
+    #[strum(to_string = "/graphql")]
     GraphQL,
+    #[strum(to_string = "/api/problems/all/")]
+    Problems,
+    #[strum(to_string = "/problems/{slug}/submit/")]
+    Submit { slug: String },
+    #[strum(to_string = "/submissions/detail/{id}/check/")]
+    Check { id: usize },
 }
 
-type QuestionDataQuery = Request<String, GraphQLResponse<QuestionList>>;
+impl LeetcodeURL {
+    /// Returns the full URL under the base URL `base`.
+    fn url(&self, base: &str) -> String {
+        format!("{}{self}", base.trim_end_matches('/'))
+    }
+}
+
+/// Returns the base URL to send LeetCode requests to, which is [`BASE_URL`] unless overridden by `LEETCODE_BASE_URL`.
+fn base_url() -> String {
+    env::var("LEETCODE_BASE_URL")
+        .ok()
+        .filter(|base| !base.is_empty())
+        .unwrap_or_else(|| String::from(BASE_URL))
+}
+
+type QuestionDataQuery = Request<String, GraphQLResponse<Option<Question>>>;
 
 impl QuestionDataQuery {
-    fn new(id: usize) -> Self {
+    fn new(slug: &str) -> Self {
+        Request::from(
+            LeetcodeURL::GraphQL.url(&base_url()),
+            Method::POST,
+            QUESTION_QUERY,
+            format!("{{\"titleSlug\": \"{slug}\"}}"),
+        )
+    }
+}
+
+type DailyChallengeQuery = Request<String, GraphQLResponse<DailyChallenge>>;
+
+impl DailyChallengeQuery {
+    fn new() -> Self {
+        Request::from(LeetcodeURL::GraphQL.url(&base_url()), Method::POST, DAILY_QUERY, String::new())
+    }
+}
+
+type QuestionListQuery = Request<String, GraphQLResponse<QuestionList>>;
+
+impl QuestionListQuery {
+    fn new(filters: &Filters, skip: usize, limit: usize) -> Self {
+        let mut json = serde_json::Map::new();
+        if let Some(difficulty) = &filters.difficulty {
+            json.insert(String::from("difficulty"), json!(difficulty.to_string().to_uppercase()));
+        }
+        if !filters.tags.is_empty() {
+            json.insert(String::from("tags"), json!(filters.tags));
+        }
+        if let Some(keywords) = &filters.keywords {
+            json.insert(String::from("searchKeywords"), json!(keywords));
+        }
+
         Request::from(
-            LeetcodeURL::GraphQL.to_string(),
+            LeetcodeURL::GraphQL.url(&base_url()),
             Method::POST,
             QUESTION_LIST_QUERY,
-            format!("{{\"skip\": \"{id}\"}}"),
+            json!({ "limit": limit, "skip": skip, "filters": json }).to_string(),
+        )
+    }
+}
+
+type SubmitRequest = Request<Value, Submission>;
+
+impl SubmitRequest {
+    fn new(base: &str, slug: &str, question_id: usize, lang: &Lang, code: &str) -> Self {
+        Request::from(
+            LeetcodeURL::Submit { slug: slug.to_string() }.url(base),
+            Method::POST,
+            "",
+            json!({ "lang": lang_slug(lang), "question_id": question_id.to_string(), "typed_code": code }),
         )
     }
 }
 
+type CheckRequest = Request<String, Check>;
+
+impl CheckRequest {
+    fn new(base: &str, id: usize) -> Self {
+        Request::from(LeetcodeURL::Check { id }.url(base), Method::GET, "", String::new())
+    }
+}
+
+type ProblemListQuery = Request<String, ProblemList>;
+
+impl ProblemListQuery {
+    fn new() -> Self {
+        Request::from(LeetcodeURL::Problems.url(&base_url()), Method::GET, "", String::new())
+    }
+}
+
+/// The list of all problems.
+#[derive(Debug, Deserialize, Serialize)]
+struct ProblemList {
+    num_total: usize,
+    stat_status_pairs: Vec<Problem>,
+}
+
+/// An entry of [`ProblemList`].
+#[derive(Debug, Deserialize, Serialize)]
+struct Problem {
+    stat: ProblemStat,
+}
+
+/// Identifiers of a [`Problem`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct ProblemStat {
+    frontend_question_id: usize,
+    #[serde(default)]
+    question_id: usize,
+    #[serde(rename = "question__title_slug")]
+    title_slug: String,
+}
+
+/// A submission of a solution.
+#[derive(Debug, Deserialize)]
+struct Submission {
+    submission_id: usize,
+}
+
+/// The result of checking on a [`Submission`], which is complete once its `state` is `SUCCESS`.
+#[derive(Debug, Deserialize)]
+struct Check {
+    state: String,
+    status_msg: Option<String>,
+    status_runtime: Option<String>,
+    runtime_percentile: Option<f64>,
+    status_memory: Option<String>,
+    memory_percentile: Option<f64>,
+    total_correct: Option<usize>,
+    total_testcases: Option<usize>,
+    full_compile_error: Option<String>,
+    full_runtime_error: Option<String>,
+    last_testcase: Option<String>,
+    expected_output: Option<String>,
+    code_output: Option<String>,
+}
+
+/// The active daily coding challenge.
+#[derive(Debug, Deserialize)]
+struct DailyChallenge {
+    question: DailyQuestion,
+}
+
+/// The question of a [`DailyChallenge`].
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DailyQuestion {
+    question_frontend_id: String,
+}
+
+/// A page of the list of questions matching some [`Filters`].
 #[derive(Debug, Deserialize)]
 struct QuestionList {
-    questions: Vec<QuestionData>,
+    total: usize,
+    questions: Vec<QuestionSummary>,
 }
 
+/// An entry of [`QuestionList`].
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
+struct QuestionSummary {
+    question_frontend_id: String,
+    title: String,
+    difficulty: String,
+    ac_rate: f64,
+    is_paid_only: bool,
+}
+
+/// The question data of a problem as queried, which is locked if the problem is premium-only and the session, if any,
+/// has no access to it.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Question {
+    Unlocked(QuestionData),
+    Locked {
+        #[serde(rename = "isPaidOnly")]
+        paid_only: bool,
+    },
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 struct QuestionData {
     question_frontend_id: String,
     content: String,
@@ -68,25 +276,228 @@ struct QuestionData {
     example_testcases: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 struct CodeSnippetJson {
     lang: String,
     code: String,
 }
 
-pub fn query(id: &str, lang: &Lang) -> Result<QuestionDetails, Box<dyn Error>> {
-    let client = Client::new();
+/// How to set a LeetCode session, for errors on actions which need one.
+const SESSION_HINT: &str = "set a LeetCode session via the environment variables `LEETCODE_SESSION` and \
+                            `LEETCODE_CSRFTOKEN`, or the `session` and `csrftoken` values of the `leetcode` source config";
+
+/// The cookies of an authenticated LeetCode session.
+struct Session {
+    token: String,
+    csrftoken: String,
+}
+
+impl Session {
+    /// Returns the session given by the environment variables `LEETCODE_SESSION` and `LEETCODE_CSRFTOKEN`, else by the
+    /// `session` and `csrftoken` values of the LeetCode source config, if any.
+    fn from(config: &Config) -> Option<Self> {
+        let value = |var: &str, key: &str| {
+            env::var(var)
+                .ok()
+                .or_else(|| {
+                    config
+                        .source
+                        .get(&Source::LeetCode.to_string())?
+                        .get(key)?
+                        .as_str()
+                        .map(String::from)
+                })
+                .filter(|value| !value.is_empty())
+        };
+
+        Some(Session {
+            token: value("LEETCODE_SESSION", "session")?,
+            csrftoken: value("LEETCODE_CSRFTOKEN", "csrftoken")?,
+        })
+    }
+
+    /// Returns `request` about the problem with title slug `slug` with the cookies of the session attached, referred
+    /// from the page of the problem under the base URL `base` as LeetCode expects.
+    fn authenticate<V: fmt::Display, T>(&self, request: Request<V, T>, base: &str, slug: &str) -> Request<V, T> {
+        request
+            .header("Cookie", format!("LEETCODE_SESSION={}; csrftoken={}", self.token, self.csrftoken))
+            .header("X-CSRFToken", self.csrftoken.clone())
+            .header("Referer", LeetcodeURL::Problem { slug: slug.to_string() }.url(base))
+    }
+}
+
+/// Returns the expected outputs listed in the examples of the problem description `content`.
+pub(super) fn parse_outputs(content: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    let re = Regex::new(r"<(?:strong|b)>Output:?\s*</(?:strong|b)>:?\s*(?:<span[^>]*>)?(?<output>[^<\n]*)")?;
+
+    Ok(re
+        .captures_iter(content)
+        .map(|caps| unescape(&caps["output"]).trim().to_string())
+        .collect())
+}
+
+/// Returns the list of all problems, going through `cache`, which is queried again if the cached list is `stale`.
+fn problems(cache: &Cache, stale: impl FnOnce(&ProblemList) -> bool) -> Result<ProblemList, Box<dyn Error>> {
+    cache.get_or_requery(&Source::LeetCode, "problems", stale, || {
+        print!("Querying problem list... ");
+        io::stdout().flush()?;
+
+        let problems = ProblemListQuery::new().response(&Client::new())?;
+        println!("{}!", "OK".green().bold());
 
-    print!("Querying question data for problem {}... ", id.cyan().bold());
+        Ok(problems)
+    })
+}
+
+/// Returns the identifiers of the problem with frontend ID `id`, going through `cache`.
+fn stat(id: usize, cache: &Cache) -> Result<ProblemStat, Box<dyn Error>> {
+    let find = |problems: &ProblemList| {
+        problems
+            .stat_status_pairs
+            .iter()
+            .find(|problem| problem.stat.frontend_question_id == id)
+            .map(|problem| problem.stat.clone())
+    };
+
+    find(&problems(cache, |problems| find(problems).map_or(true, |stat| stat.question_id == 0))?)
+        .ok_or_else(|| format!("Can't find problem {id} in problem list of {}", Source::LeetCode).into())
+}
+
+/// Returns the LeetCode slug of language `lang`.
+fn lang_slug(lang: &Lang) -> &'static str {
+    match lang {
+        Lang::Cpp => "cpp",
+        Lang::Go => "golang",
+        Lang::Java => "java",
+        Lang::Python => "python3",
+        Lang::Rust => "rust",
+        Lang::TypeScript => "typescript",
+    }
+}
+
+/// Returns the zero-padded frontend ID of the problem referred to by `reference`, which is either its frontend ID,
+/// its title slug, or its URL.
+pub fn problem_id(reference: &str, cache: &Cache) -> Result<String, Box<dyn Error>> {
+    if let Ok(id) = reference.parse::<usize>() {
+        let num_total = problems(cache, |problems| id > problems.num_total)?.num_total;
+
+        return if (1..=num_total).contains(&id) {
+            Ok(format!("{id:0>4}"))
+        } else {
+            Err(format!("{id} is not a problem ID of {} in range 1..={num_total}", Source::LeetCode).into())
+        };
+    }
+
+    let slug = Regex::new(r"^(?:https?://(?:www\.)?leetcode\.com/problems/)?(?<slug>[a-z0-9-]+)(?:/.*)?$")?
+        .captures(reference)
+        .map(|caps| caps["slug"].to_string())
+        .ok_or_else(|| format!("{reference} is neither a problem ID, title slug nor URL of {}", Source::LeetCode))?;
+    let find = |problems: &ProblemList| {
+        problems
+            .stat_status_pairs
+            .iter()
+            .find(|problem| problem.stat.title_slug == slug)
+            .map(|problem| format!("{:0>4}", problem.stat.frontend_question_id))
+    };
+
+    find(&problems(cache, |problems| find(problems).is_none())?)
+        .ok_or_else(|| format!("Can't find problem with title slug {slug} in problem list of {}", Source::LeetCode).into())
+}
+
+/// Returns the zero-padded frontend ID of the already fetched problem referred to by `reference` as [`problem_id`]
+/// does, except that a frontend ID is padded as is instead of being checked against the problem list, so that it's
+/// resolved without the network.
+pub fn fetched_problem_id(reference: &str, cache: &Cache) -> Result<String, Box<dyn Error>> {
+    match reference.parse::<usize>() {
+        Ok(id) if id > 0 => Ok(format!("{id:0>4}")),
+        _ => problem_id(reference, cache),
+    }
+}
+
+/// Returns the zero-padded frontend ID of today's daily challenge problem, going through `cache`.
+pub fn daily(cache: &Cache) -> Result<String, Box<dyn Error>> {
+    print!("Querying daily challenge... ");
     io::stdout().flush()?;
 
-    let question = &QuestionDataQuery::new(id.parse::<usize>()?.saturating_sub(1))
-        .response(&client)?
+    let id = DailyChallengeQuery::new()
+        .response(&Client::new())?
         .data
-        .questions[0];
-    assert!(format!("{:0>4}", question.question_frontend_id) == id);
+        .question
+        .question_frontend_id;
+    println!("{}!", "OK".green().bold());
+
+    problem_id(&id, cache)
+}
+
+/// Returns the page of `limit` problems matching `filters`, after skipping the first `skip` of them.
+pub fn list(filters: &Filters, skip: usize, limit: usize) -> Result<Page, Box<dyn Error>> {
+    print!("Querying problem list... ");
+    io::stdout().flush()?;
+
+    let list = QuestionListQuery::new(filters, skip, limit)
+        .response(&Client::new())?
+        .data;
     println!("{}!", "OK".green().bold());
 
+    Ok(Page {
+        listings: list
+            .questions
+            .into_iter()
+            .map(|question| {
+                Ok(Listing {
+                    id: ProblemId(format!("{:0>4}", question.question_frontend_id)),
+                    title: question.title,
+                    difficulty: question.difficulty.parse::<Difficulty>()?,
+                    acceptance: question.ac_rate,
+                    paid_only: question.is_paid_only,
+                })
+            })
+            .collect::<Result<_, Box<dyn Error>>>()?,
+        total: list.total,
+    })
+}
+
+pub fn query(id: &str, lang: &Lang, config: &Config, cache: &Cache) -> Result<QuestionDetails, Box<dyn Error>> {
+    let question = &cache.get_or_query(&Source::LeetCode, id, || {
+        let frontend_id = id.parse::<usize>()?;
+        let slug = stat(frontend_id, cache)?.title_slug;
+
+        print!("Querying question data for problem {}... ", id.cyan().bold());
+        io::stdout().flush()?;
+
+        let session = Session::from(config);
+        let mut request = QuestionDataQuery::new(&slug);
+        if let Some(session) = &session {
+            request = session.authenticate(request, &base_url(), &slug);
+        }
+
+        let question = match request.response(&Client::new())?.data {
+            Some(Question::Unlocked(question)) => question,
+            Some(Question::Locked { paid_only: true }) if session.is_none() => {
+                return Err(format!("Problem {id} is premium-only, {SESSION_HINT}").into());
+            }
+            Some(Question::Locked { paid_only: true }) => {
+                return Err(format!(
+                    "Problem {id} is premium-only, and the LeetCode session has no access to it, possibly as it has \
+                     expired"
+                )
+                .into());
+            }
+            Some(Question::Locked { paid_only: false }) => {
+                return Err(format!("Can't parse question data for problem with title slug {slug}").into());
+            }
+            None => return Err(format!("No question data for problem with title slug {slug}").into()),
+        };
+        if question.question_frontend_id.parse::<usize>()? != frontend_id {
+            return Err(
+                format!("Queried question data is of problem {} instead of problem {id}", question.question_frontend_id).into(),
+            );
+        }
+        println!("{}!", "OK".green().bold());
+
+        Ok(question)
+    })?;
+
     let mut metadata_json = question.meta_data.clone();
     metadata_json.insert_str(3, format!("  \"lang\": \"{lang}\",\r\n").as_str());
 
@@ -105,5 +516,254 @@ pub fn query(id: &str, lang: &Lang) -> Result<QuestionDetails, Box<dyn Error>> {
             }),
         serde_json::from_str(&metadata_json)?,
         question.example_testcases.clone(),
+        parse_outputs(&question.content)?,
     ))
 }
+
+/// Submits `code` as the solution to problem `id` in language `lang`, and returns the verdict on it once judged.
+pub fn submit(id: &str, lang: &Lang, code: &str, config: &Config, cache: &Cache) -> Result<Verdict, Box<dyn Error>> {
+    let session = Session::from(config).ok_or_else(|| format!("Submitting solutions needs a session, {SESSION_HINT}"))?;
+    let stat = stat(id.parse::<usize>()?, cache)?;
+
+    judge(&base_url(), id, &stat, lang, code, &session)
+}
+
+/// Submits `code` as the solution in language `lang` to problem `id` identified by `stat` with `session` to LeetCode
+/// at the base URL `base`, and polls for the verdict on it until judged.
+fn judge(
+    base: &str, id: &str, stat: &ProblemStat, lang: &Lang, code: &str, session: &Session,
+) -> Result<Verdict, Box<dyn Error>> {
+    let client = Client::new();
+
+    print!("Submitting solution to problem {}... ", id.cyan().bold());
+    io::stdout().flush()?;
+
+    let submission = session
+        .authenticate(SubmitRequest::new(base, &stat.title_slug, stat.question_id, lang, code), base, &stat.title_slug)
+        .response(&client)?;
+    println!("{}!", "OK".green().bold());
+
+    print!("Waiting for verdict on submission {}... ", submission.submission_id.to_string().cyan().bold());
+    io::stdout().flush()?;
+
+    for _ in 0..MAX_POLLS {
+        thread::sleep(POLL_INTERVAL);
+
+        let check = session
+            .authenticate(CheckRequest::new(base, submission.submission_id), base, &stat.title_slug)
+            .response(&client)?;
+        if check.state != "SUCCESS" {
+            continue;
+        }
+        println!("{}!", "OK".green().bold());
+
+        let status = check.status_msg.unwrap_or_else(|| String::from("Unknown"));
+        let failing_testcase = check
+            .last_testcase
+            .filter(|testcase| !testcase.is_empty())
+            .map(|testcase| {
+                format!(
+                    "Input:\n{testcase}\nExpected:\n{}\nOutput:\n{}",
+                    check.expected_output.unwrap_or_default(),
+                    check.code_output.unwrap_or_default()
+                )
+            });
+        let details = check
+            .full_compile_error
+            .or(check.full_runtime_error)
+            .or(failing_testcase);
+
+        return Ok(Verdict {
+            accepted: status == "Accepted",
+            status,
+            runtime: check.status_runtime,
+            runtime_percentile: check.runtime_percentile,
+            memory: check.status_memory,
+            memory_percentile: check.memory_percentile,
+            passed: check.total_correct,
+            total: check.total_testcases,
+            details,
+        });
+    }
+
+    Err(format!("No verdict on submission {} after {MAX_POLLS} polls", submission.submission_id).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read};
+    use std::net::TcpListener;
+    use std::sync::{Mutex, OnceLock};
+
+    use super::*;
+
+    /// The title slugs of the problems the mock server judges, each submission to which gets the index of its slug
+    /// plus one as its ID: one accepted, one with a wrong answer, one pending forever, and one failing to be checked.
+    const SLUGS: [&str; 4] = ["accepted", "wrong-answer", "pending", "http-error"];
+
+    /// The path and `Referer` header of each request received by the mock server.
+    static REQUESTS: Mutex<Vec<(String, Option<String>)>> = Mutex::new(vec![]);
+
+    /// Returns the status and body of the response of the mock server to a request for `path`.
+    fn respond(path: &str) -> (&'static str, String) {
+        let submitted = SLUGS
+            .iter()
+            .position(|slug| path == LeetcodeURL::Submit { slug: slug.to_string() }.to_string());
+        let checked = (1..=SLUGS.len()).find(|&id| path == LeetcodeURL::Check { id }.to_string());
+
+        match (submitted, checked) {
+            (Some(i), _) => ("200 OK", json!({ "submission_id": i + 1 }).to_string()),
+            (_, Some(1)) => (
+                "200 OK",
+                json!({
+                    "state": "SUCCESS",
+                    "status_msg": "Accepted",
+                    "status_runtime": "0 ms",
+                    "runtime_percentile": 100.0,
+                    "status_memory": "2.2 MB",
+                    "memory_percentile": 87.5,
+                    "total_correct": 63,
+                    "total_testcases": 63
+                })
+                .to_string(),
+            ),
+            (_, Some(2)) => (
+                "200 OK",
+                json!({
+                    "state": "SUCCESS",
+                    "status_msg": "Wrong Answer",
+                    "total_correct": 2,
+                    "total_testcases": 63,
+                    "last_testcase": "[3,2,4]\n6",
+                    "expected_output": "[1,2]",
+                    "code_output": "[0,1]"
+                })
+                .to_string(),
+            ),
+            (_, Some(3)) => ("200 OK", json!({ "state": "PENDING" }).to_string()),
+            (_, Some(_)) => ("500 Internal Server Error", String::from("{}")),
+            _ => ("404 Not Found", String::from("{}")),
+        }
+    }
+
+    /// Returns the base URL of the mock server, which is started on first use.
+    fn mock_server() -> &'static str {
+        static BASE_URL: OnceLock<String> = OnceLock::new();
+
+        BASE_URL.get_or_init(|| {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    let mut reader = BufReader::new(&stream);
+
+                    let mut request_line = String::new();
+                    reader.read_line(&mut request_line).unwrap();
+                    let (mut referer, mut length) = (None, 0);
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        let Some((name, value)) = line.trim_end().split_once(": ") else {
+                            break;
+                        };
+                        match name.to_lowercase().as_str() {
+                            "referer" => referer = Some(value.to_string()),
+                            "content-length" => length = value.parse().unwrap(),
+                            _ => {}
+                        }
+                    }
+                    reader.by_ref().take(length).read_to_end(&mut vec![]).unwrap();
+
+                    let path = request_line.split_whitespace().nth(1).unwrap().to_string();
+                    let (status, body) = respond(&path);
+                    REQUESTS.lock().unwrap().push((path, referer));
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                }
+            });
+
+            base_url
+        })
+    }
+
+    /// Returns the verdict on a submission to the problem with title slug `slug` of the mock server.
+    fn judge_mock(slug: &str) -> Result<Verdict, Box<dyn Error>> {
+        let stat = ProblemStat { frontend_question_id: 1, question_id: 1, title_slug: slug.to_string() };
+        let session = Session { token: String::from("token"), csrftoken: String::from("csrftoken") };
+
+        judge(mock_server(), "0001", &stat, &Lang::Rust, "", &session)
+    }
+
+    /// Returns the `Referer` headers of the requests received by the mock server about the problem with title slug
+    /// `slug`.
+    fn referers(slug: &str) -> Vec<Option<String>> {
+        let id = SLUGS.iter().position(|s| *s == slug).unwrap() + 1;
+        let paths = [
+            LeetcodeURL::Submit { slug: slug.to_string() }.to_string(),
+            LeetcodeURL::Check { id }.to_string(),
+        ];
+
+        REQUESTS
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(path, _)| paths.contains(path))
+            .map(|(_, referer)| referer.clone())
+            .collect()
+    }
+
+    #[test]
+    fn accepted_submission() {
+        let verdict = judge_mock("accepted").unwrap();
+
+        assert!(verdict.accepted);
+        assert_eq!(verdict.status, "Accepted");
+        assert_eq!(verdict.runtime.as_deref(), Some("0 ms"));
+        assert_eq!(verdict.memory_percentile, Some(87.5));
+        assert_eq!((verdict.passed, verdict.total), (Some(63), Some(63)));
+        assert_eq!(verdict.details, None);
+
+        let problem_url = format!("{}/problems/accepted/", mock_server());
+        let referers = referers("accepted");
+        assert!(!referers.is_empty());
+        assert!(referers
+            .iter()
+            .all(|referer| referer.as_ref() == Some(&problem_url)));
+    }
+
+    #[test]
+    fn wrong_answer_submission() {
+        let verdict = judge_mock("wrong-answer").unwrap();
+
+        assert!(!verdict.accepted);
+        assert_eq!(verdict.status, "Wrong Answer");
+        assert_eq!((verdict.passed, verdict.total), (Some(2), Some(63)));
+        assert_eq!(verdict.details.as_deref(), Some("Input:\n[3,2,4]\n6\nExpected:\n[1,2]\nOutput:\n[0,1]"));
+    }
+
+    #[test]
+    fn pending_submission_gives_up_after_max_polls() {
+        let err = judge_mock("pending").unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains(&format!("after {MAX_POLLS} polls")));
+        // One submit request, then one check request per poll
+        assert_eq!(referers("pending").len(), 1 + MAX_POLLS);
+    }
+
+    #[test]
+    fn failed_check_request_is_an_error() {
+        let err = judge_mock("http-error").unwrap_err();
+
+        assert!(err.to_string().contains("500"));
+        assert_eq!(referers("http-error").len(), 2);
+    }
+}
@@ -0,0 +1,40 @@
+use strum::{Display, EnumString};
+
+use super::ProblemId;
+
+/// Difficulties of problems.
+#[derive(Clone, Debug, Display, EnumString, PartialEq)]
+#[strum(ascii_case_insensitive)]
+pub enum Difficulty {
+    #[strum(serialize = "easy", to_string = "Easy")]
+    Easy,
+    #[strum(serialize = "medium", to_string = "Medium")]
+    Medium,
+    #[strum(serialize = "hard", to_string = "Hard")]
+    Hard,
+}
+
+/// Filters on the problems listed by a source.
+#[derive(Debug, Default)]
+pub struct Filters {
+    pub difficulty: Option<Difficulty>,
+    pub tags: Vec<String>,
+    pub keywords: Option<String>,
+}
+
+/// A problem listed by a source.
+#[derive(Debug)]
+pub struct Listing {
+    pub id: ProblemId,
+    pub title: String,
+    pub difficulty: Difficulty,
+    pub acceptance: f64,
+    pub paid_only: bool,
+}
+
+/// A page of [`Listing`]s, along with the total number of problems matching the filters.
+#[derive(Debug)]
+pub struct Page {
+    pub listings: Vec<Listing>,
+    pub total: usize,
+}
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufReader, Write};
+use std::path::PathBuf;
+
+use colored::Colorize;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::modules::colorize::MoreColorize;
+use crate::modules::config::Config;
+use crate::modules::lang::Lang;
+
+use super::leetcode::parse_outputs;
+use super::{QuestionDetails, Source};
+
+/// A problem definition file, detailing a problem in the same shape as [`QuestionDetails`].
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProblemFile {
+    /// The problem description in HTML.
+    description: String,
+    /// The starter code of the problem, keyed by language extension.
+    #[serde(default)]
+    code: HashMap<String, String>,
+    /// The LeetCode-style metadata of the problem, without its `lang` field.
+    meta_data: Value,
+    example_testcases: String,
+    /// The expected outputs of the example testcases, parsed from the description if absent.
+    outputs: Option<Vec<String>>,
+}
+
+/// Returns the result of reading data associated to problem `id` in language `lang` from the problem file `{id}.json`
+/// under the directory configured for local problems.
+pub fn query(id: &str, lang: &Lang, config: &Config) -> Result<QuestionDetails, Box<dyn Error>> {
+    let dir = config
+        .source
+        .get(&Source::Local.to_string())
+        .and_then(|localconf| localconf.get("dir"))
+        .and_then(Value::as_str)
+        .ok_or_else(|| format!("Can't find entry for dir of {} in source of config", Source::Local))?;
+    let problem_file = PathBuf::from(dir).join(format!("{id}.json"));
+
+    print!("Reading problem file {}... ", problem_file.display().to_string().orange().bold());
+    io::stdout().flush()?;
+
+    let problem: ProblemFile = serde_json::from_reader(BufReader::new(File::open(&problem_file)?))?;
+    println!("{}!", "OK".green().bold());
+
+    let mut metadata = problem.meta_data;
+    metadata
+        .as_object_mut()
+        .ok_or("metaData of problem file isn't an object")?
+        .insert(String::from("lang"), Value::String(lang.to_string()));
+
+    let outputs = match problem.outputs {
+        Some(outputs) => outputs,
+        None => parse_outputs(&problem.description)?,
+    };
+
+    Ok((
+        problem.description,
+        problem.code.get(&lang.to_string()).cloned(),
+        serde_json::from_value(metadata)?,
+        problem.example_testcases,
+        outputs,
+    ))
+}
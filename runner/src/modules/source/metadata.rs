@@ -1,3 +1,4 @@
+use serde::de::Error;
 use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::modules::lang::Lang;
@@ -10,13 +11,42 @@ pub struct Typ {
     pub form: Form,
 }
 
+impl Typ {
+    /// Returns the underlying element data type of [`Typ`], stripped of any array nesting.
+    pub fn base(&self) -> &str {
+        let mut base = self.initial.trim();
+
+        loop {
+            if let Some(inner) = base.strip_prefix("list<").and_then(|s| s.strip_suffix('>')) {
+                base = inner;
+            } else if let Some(inner) = base.strip_suffix("[]") {
+                base = inner;
+            } else {
+                return base;
+            }
+        }
+    }
+}
+
+/// The form of a data type.
 #[derive(Debug, Deserialize, PartialEq, Serialize)]
 pub enum Form {
     Unit,
-    Array,
+    Array(Box<Form>),
     Pointer,
 }
 
+impl Form {
+    /// Returns whether [`Form`] is, or is an array nesting of, a [`Form::Pointer`].
+    pub fn has_pointer(&self) -> bool {
+        match self {
+            Form::Unit => false,
+            Form::Array(inner) => inner.has_pointer(),
+            Form::Pointer => true,
+        }
+    }
+}
+
 /// A structure defining the name and type for a variable.
 #[derive(Debug, Deserialize)]
 pub struct Variable {
@@ -25,24 +55,117 @@ pub struct Variable {
     pub typ: Typ,
 }
 
-/// A structure defining the metadata associated to a question.
+/// A structure defining the signature of a function.
 #[derive(Debug)]
-pub struct MetaData {
+pub struct Function {
     pub name: String,
     pub params: Vec<Variable>,
     pub return_type: Typ,
-    pub cleanup: bool,
+    pub output_index: Option<usize>,
+}
+
+impl Function {
+    /// Returns the parameter which [`Function`] mutates in-place as its output, if it doesn't return a value.
+    pub fn mutated(&self) -> Option<&Variable> {
+        if self.return_type.initial == "void" {
+            self.params.get(self.output_index.unwrap_or(0))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the data types of the parameters and return value of [`Function`].
+    fn types(&self) -> impl Iterator<Item = &Typ> {
+        self.params
+            .iter()
+            .map(|v| &v.typ)
+            .chain([&self.return_type])
+    }
+}
+
+/// An enum defining the metadata associated to a question.
+#[derive(Debug)]
+pub enum MetaData {
+    /// A question asking for the implementation of a function.
+    Function(Function),
+    /// A question asking for the design of a class, constructed with `constructor` and offering `methods`.
+    Class {
+        name: String,
+        constructor: Vec<Variable>,
+        methods: Vec<Function>,
+    },
+    /// A question asking for a program reading its input from stdin and writing its output to stdout.
+    Stdio,
+}
+
+impl MetaData {
+    /// Returns all data types used by the question.
+    fn types(&self) -> Vec<&Typ> {
+        match self {
+            MetaData::Function(function) => function.types().collect(),
+            MetaData::Class { constructor, methods, .. } => constructor
+                .iter()
+                .map(|v| &v.typ)
+                .chain(methods.iter().flat_map(Function::types))
+                .collect(),
+            MetaData::Stdio => vec![],
+        }
+    }
+
+    /// Returns the names of the data structures used by the question, in order of first appearance.
+    pub fn datastructs(&self) -> Vec<&str> {
+        let mut datastructs = vec![];
+
+        for typ in self.types() {
+            if typ.form.has_pointer() && !datastructs.contains(&typ.base()) {
+                datastructs.push(typ.base());
+            }
+        }
+
+        datastructs
+    }
+
+    /// Returns whether the question uses pointers which need to be cleaned up.
+    pub fn cleanup(&self) -> bool {
+        self.types().iter().any(|typ| typ.form == Form::Pointer)
+    }
 }
 
 impl<'de> Deserialize<'de> for MetaData {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         #[derive(Deserialize)]
-        struct PreMetaData {
-            lang: Lang,
+        #[serde(untagged)]
+        enum PreMetaData {
+            Class {
+                lang: Lang,
+                classname: String,
+                constructor: PreConstructor,
+                methods: Vec<PreFunction>,
+            },
+            Function {
+                lang: Lang,
+                #[serde(flatten)]
+                function: PreFunction,
+            },
+        }
+
+        #[derive(Deserialize)]
+        struct PreConstructor {
+            params: Vec<PreVariable>,
+        }
+
+        #[derive(Deserialize)]
+        struct PreFunction {
             name: String,
             params: Vec<PreVariable>,
             #[serde(rename = "return")]
             return_type: Return,
+            output: Option<Output>,
+        }
+
+        #[derive(Deserialize)]
+        struct Output {
+            paramindex: usize,
         }
 
         #[derive(Deserialize)]
@@ -58,27 +181,34 @@ impl<'de> Deserialize<'de> for MetaData {
             typ: String,
         }
 
-        let pre_metadata = PreMetaData::deserialize(deserializer)?;
-        let return_type = pre_metadata
-            .lang
-            .parse(&pre_metadata.return_type.typ)
-            .unwrap();
-        let mut cleanup = return_type.form == Form::Pointer;
-
-        Ok(MetaData {
-            name: pre_metadata.name,
-            params: pre_metadata
-                .params
-                .iter()
-                .map(|v| {
-                    let typ = pre_metadata.lang.parse(&v.typ).unwrap();
-                    cleanup |= typ.form == Form::Pointer;
+        fn variables<E: Error>(lang: &Lang, params: Vec<PreVariable>) -> Result<Vec<Variable>, E> {
+            params
+                .into_iter()
+                .map(|v| Ok(Variable { typ: lang.parse(&v.typ).map_err(Error::custom)?, name: v.name }))
+                .collect()
+        }
 
-                    Variable { name: v.name.clone(), typ }
-                })
-                .collect(),
-            return_type,
-            cleanup,
+        fn function<E: Error>(lang: &Lang, function: PreFunction) -> Result<Function, E> {
+            Ok(Function {
+                name: function.name,
+                params: variables(lang, function.params)?,
+                return_type: lang
+                    .parse(&function.return_type.typ)
+                    .map_err(Error::custom)?,
+                output_index: function.output.map(|output| output.paramindex),
+            })
+        }
+
+        Ok(match PreMetaData::deserialize(deserializer)? {
+            PreMetaData::Class { lang, classname, constructor, methods } => MetaData::Class {
+                name: classname,
+                constructor: variables(&lang, constructor.params)?,
+                methods: methods
+                    .into_iter()
+                    .map(|m| function(&lang, m))
+                    .collect::<Result<_, _>>()?,
+            },
+            PreMetaData::Function { lang, function: f } => MetaData::Function(function(&lang, f)?),
         })
     }
 }
@@ -1,14 +1,25 @@
+mod cache;
+mod codeforces;
 mod leetcode;
+mod listing;
+mod local;
 mod metadata;
+mod verdict;
 
 use std::error::Error;
+use std::fmt::{self, Formatter};
+use std::ops::Deref;
 
 use serde::Serialize;
 use strum::{Display, EnumIter, EnumString};
 
+use super::config::Config;
 use super::lang::Lang;
 
-pub use metadata::{Form, MetaData, Typ};
+pub use cache::Cache;
+pub use listing::{Difficulty, Filters, Listing, Page};
+pub use metadata::{Form, Function, MetaData, Typ, Variable};
+pub use verdict::Verdict;
 
 /// Sources of coding challenge questions.
 #[derive(Clone, Debug, Default, Display, EnumIter, EnumString, PartialEq, Serialize)]
@@ -17,16 +28,106 @@ pub enum Source {
     #[strum(serialize = "leetcode")]
     #[default]
     LeetCode,
+    #[strum(serialize = "codeforces")]
+    Codeforces,
+    #[strum(serialize = "local")]
+    Local,
 }
 
 impl Source {
-    /// Returns the result of querying data associated to problem `id` in language `lang`.
-    pub fn query(&self, id: &str, lang: &Lang) -> Result<QuestionDetails, Box<dyn Error>> {
+    /// Returns the result of querying data associated to problem `id` in language `lang`, going through `cache`.
+    pub fn query(&self, id: &str, lang: &Lang, config: &Config, cache: &Cache) -> Result<QuestionDetails, Box<dyn Error>> {
         match self {
-            Source::LeetCode => leetcode::query(id, lang),
+            Source::LeetCode => leetcode::query(id, lang, config, cache),
+            Source::Codeforces => codeforces::query(id, lang, cache),
+            Source::Local => local::query(id, lang, config),
         }
     }
+
+    /// Returns the ID of the problem referred to by `reference`, validated against the source.
+    pub fn problem_id(&self, reference: &str, cache: &Cache) -> Result<ProblemId, Box<dyn Error>> {
+        let alphanumeric = |extra: &str| {
+            reference
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || extra.contains(c))
+        };
+
+        match self {
+            Source::LeetCode => leetcode::problem_id(reference, cache).map(ProblemId),
+            Source::Codeforces if alphanumeric("") => Ok(ProblemId(reference.to_uppercase())),
+            Source::Local if alphanumeric("-_") => Ok(ProblemId(String::from(reference))),
+            _ => Err(format!("{reference} is not a problem ID of {self}").into()),
+        }
+    }
+
+    /// Returns the ID of the already fetched problem referred to by `reference`, which for a LeetCode frontend ID
+    /// needs neither the network nor the cached problem list.
+    pub fn fetched_problem_id(&self, reference: &str, cache: &Cache) -> Result<ProblemId, Box<dyn Error>> {
+        match self {
+            Source::LeetCode => leetcode::fetched_problem_id(reference, cache).map(ProblemId),
+            _ => self.problem_id(reference, cache),
+        }
+    }
+
+    /// Returns the ID of today's daily challenge problem of the source, going through `cache`.
+    pub fn daily(&self, cache: &Cache) -> Result<ProblemId, Box<dyn Error>> {
+        match self {
+            Source::LeetCode => leetcode::daily(cache).map(ProblemId),
+            _ => Err(format!("{self} has no daily challenge").into()),
+        }
+    }
+
+    /// Returns the page of `limit` problems of the source matching `filters`, after skipping the first `skip` of them.
+    pub fn list(&self, filters: &Filters, skip: usize, limit: usize) -> Result<Page, Box<dyn Error>> {
+        match self {
+            Source::LeetCode => leetcode::list(filters, skip, limit),
+            _ => Err(format!("Listing problems of {self} isn't supported").into()),
+        }
+    }
+
+    /// Submits `code` as the solution to problem `id` in language `lang`, and returns the verdict on it once judged.
+    pub fn submit(&self, id: &str, lang: &Lang, code: &str, config: &Config, cache: &Cache) -> Result<Verdict, Box<dyn Error>> {
+        match self {
+            Source::LeetCode => leetcode::submit(id, lang, code, config, cache),
+            _ => Err(format!("Submitting solutions to {self} isn't supported").into()),
+        }
+    }
+
+    /// Returns whether solutions to problems of the source are judged by their output to stdin samples, instead of
+    /// by tests against a function or class.
+    pub fn stdio(&self) -> bool {
+        matches!(self, Source::Codeforces)
+    }
+}
+
+/// The ID of a problem in the naming format of its source, e.g. zero-padded to at least 4 digits for LeetCode, as
+/// obtained from [`Source::problem_id`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProblemId(String);
+
+impl Deref for ProblemId {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for ProblemId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
 }
 
 /// An alias to a tuple detailing information for a question.
-type QuestionDetails = (String, Option<String>, MetaData, String);
+type QuestionDetails = (String, Option<String>, MetaData, String, Vec<String>);
+
+/// Returns `html` with its HTML character entities unescaped.
+fn unescape(html: &str) -> String {
+    html.replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
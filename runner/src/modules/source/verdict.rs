@@ -0,0 +1,17 @@
+/// The verdict on a solution submitted to a source.
+#[derive(Debug)]
+pub struct Verdict {
+    /// The status of the submission, e.g. `Accepted` or `Wrong Answer`.
+    pub status: String,
+    pub accepted: bool,
+    pub runtime: Option<String>,
+    /// The percentage of submissions in the same language that the runtime beats.
+    pub runtime_percentile: Option<f64>,
+    pub memory: Option<String>,
+    /// The percentage of submissions in the same language that the memory usage beats.
+    pub memory_percentile: Option<f64>,
+    pub passed: Option<usize>,
+    pub total: Option<usize>,
+    /// Details of what went wrong, e.g. the compile error, or the failing testcase with its expected and actual output.
+    pub details: Option<String>,
+}
@@ -0,0 +1,77 @@
+use std::error::Error;
+use std::fs;
+
+use colored::Colorize;
+
+use super::colorize::MoreColorize;
+use super::config::Config;
+use super::lang::Lang;
+use super::source::{Cache, Source, Verdict};
+
+/// The marker commented on the line before the solution code.
+const START_MARKER: &str = ">>>>>";
+
+/// The marker commented on the line after the solution code.
+const END_MARKER: &str = "<<<<<";
+
+/// Returns the solution code in `sol`, which is between the lines commenting [`START_MARKER`] and [`END_MARKER`] in
+/// language `lang`.
+fn solution_code(sol: &str, lang: &Lang) -> Option<String> {
+    let is_marker = |line: &str, marker: &str| {
+        lang.comments().iter().any(|c| {
+            line.trim()
+                .strip_prefix(c.trim())
+                .is_some_and(|rest| rest.trim() == marker)
+        })
+    };
+
+    let lines = sol.lines().collect::<Vec<_>>();
+    let start = lines.iter().position(|l| is_marker(l, START_MARKER))? + 1;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|l| is_marker(l, END_MARKER))?;
+
+    Some(lines[start..end].join("\n"))
+}
+
+/// Submits the solution to problem `id` of `source` in language `lang`, and prints the verdict on it.
+pub fn submit(id: &str, lang: &Lang, source: &Source, config: &Config, cache: &Cache) -> Result<(), Box<dyn Error>> {
+    let solfile = config.prob_dir(source, id).join(format!("sol.{lang}"));
+    let sol = fs::read_to_string(&solfile).map_err(|err| format!("Can't read solution {}: {err}", solfile.display()))?;
+    let code = solution_code(&sol, lang).ok_or_else(|| {
+        format!(
+            "Can't find solution code between `{START_MARKER}` and `{END_MARKER}` markers in {}",
+            solfile.display()
+        )
+    })?;
+
+    println!("Extracted solution code from {}", solfile.display().to_string().orange().bold());
+
+    print_verdict(&source.submit(id, lang, &code, config, cache)?);
+
+    Ok(())
+}
+
+/// Prints `verdict` along with its runtime and memory usage, and the details of what went wrong if any.
+fn print_verdict(verdict: &Verdict) {
+    let beating = |percentile: Option<f64>| percentile.map_or_else(String::new, |p| format!(", beating {p:.2}%"));
+
+    println!(
+        "\n{}: {}",
+        "VERDICT".yellow().bold(),
+        if verdict.accepted { verdict.status.green().bold() } else { verdict.status.red().bold() }
+    );
+    if let (Some(passed), Some(total)) = (verdict.passed, verdict.total) {
+        println!("Passed {passed}/{total} testcases");
+    }
+    if let Some(runtime) = &verdict.runtime {
+        println!("Runtime: {}{}", runtime.cyan().bold(), beating(verdict.runtime_percentile));
+    }
+    if let Some(memory) = &verdict.memory {
+        println!("Memory: {}{}", memory.cyan().bold(), beating(verdict.memory_percentile));
+    }
+    if let Some(details) = &verdict.details {
+        println!("\n{}:\n{details}", "DETAILS".yellow().bold());
+    }
+}
@@ -0,0 +1,49 @@
+#!/usr/bin/env bash
+# A wrapper around `go test -c`, compiling the test file FILE into the test binary given by `-o OUTPUT`, against the
+# `proctor` library module given by `--lib DIR`. With `--stdio`, FILE is instead built as a program via `go build`.
+set -e
+
+while [[ $# -gt 0 ]]; do
+    case "$1" in
+        -o)
+            output="$2"
+            shift 2
+            ;;
+        --lib)
+            lib="$2"
+            shift 2
+            ;;
+        --stdio)
+            stdio=1
+            shift
+            ;;
+        *)
+            file="$1"
+            shift
+            ;;
+    esac
+done
+
+[[ "$output" == /* ]] || output="$PWD/$output"
+[[ "$lib" == /* ]] || lib="$PWD/$lib"
+
+build=$(mktemp -d)
+trap 'rm -rf "$build"' EXIT
+
+cp "$file" "$build/$([[ -n "$stdio" ]] && echo main.go || echo sol_test.go)"
+cat > "$build/go.mod" <<MOD
+module solution
+
+go 1.21
+
+require proctor v0.0.0
+
+replace proctor => $lib
+MOD
+
+cd "$build"
+if [[ -n "$stdio" ]]; then
+    go build -o "$output"
+else
+    go test -c -o "$output"
+fi
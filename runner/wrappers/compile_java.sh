@@ -0,0 +1,18 @@
+#!/usr/bin/env bash
+# A wrapper around `javac`, compiling into the class directory given by `-o OUTPUT`.
+
+args=()
+while [[ $# -gt 0 ]]; do
+    case "$1" in
+        -o)
+            args+=("-d" "$2")
+            shift 2
+            ;;
+        *)
+            args+=("$1")
+            shift
+            ;;
+    esac
+done
+
+exec javac "${args[@]}"
@@ -0,0 +1,45 @@
+#!/usr/bin/env bash
+# A wrapper around `tsc`, type-checking FILE and emitting it into the directory given by `-o OUTPUT`, against the
+# `proctor` library package given by `--lib DIR`.
+set -e
+
+while [[ $# -gt 0 ]]; do
+    case "$1" in
+        -o)
+            output="$2"
+            shift 2
+            ;;
+        --lib)
+            lib="$2"
+            shift 2
+            ;;
+        *)
+            file="$1"
+            shift
+            ;;
+    esac
+done
+
+[[ "$output" == /* ]] || output="$PWD/$output"
+[[ "$lib" == /* ]] || lib="$PWD/$lib"
+[[ "$file" == /* ]] || file="$PWD/$file"
+
+build=$(mktemp -d)
+trap 'rm -rf "$build"' EXIT
+
+cat > "$build/tsconfig.json" <<CONFIG
+{
+  "compilerOptions": {
+    "target": "es2022",
+    "module": "commonjs",
+    "rootDir": "$(dirname "$file")",
+    "outDir": "$output",
+    "baseUrl": "$build",
+    "paths": { "proctor/*": ["$lib/build/proctor/*"] },
+    "typeRoots": ["$lib/node_modules/@types"]
+  },
+  "files": ["$file"]
+}
+CONFIG
+
+exec "$lib/node_modules/.bin/tsc" --pretty -p "$build/tsconfig.json"